@@ -0,0 +1,125 @@
+use proc_macro2::{Ident, Span};
+use syn::meta::ParseNestedMeta;
+use syn::{Error, LitStr, Result};
+
+use crate::compare::{Numbers, Order, UnderscoreOrder};
+
+// Byte order promises the ascending order of `Ord for str`.
+const BYTES_DESC: &str = "desc is not supported with order = \"bytes\"";
+
+#[derive(Default)]
+pub struct Args {
+    pub like_trait: Option<LikeTrait>,
+    pub export: Option<Span>,
+    pub by: Option<syn::Path>,
+    pub desc: bool,
+    pub order: Option<Order>,
+    pub numbers: Option<Numbers>,
+    pub underscores: Option<UnderscoreOrder>,
+    pub or_patterns: Option<Span>,
+    pub unique: bool,
+}
+
+pub struct LikeTrait {
+    pub span: Span,
+    pub idents: Vec<Ident>,
+}
+
+impl Args {
+    pub fn parse_meta(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+        if meta.path.is_ident("like_trait") {
+            if self.like_trait.is_some() {
+                return Err(meta.error("duplicate like_trait argument"));
+            }
+            let mut idents = Vec::new();
+            meta.parse_nested_meta(|meta| {
+                idents.push(meta.path.require_ident()?.clone());
+                Ok(())
+            })?;
+            self.like_trait = Some(LikeTrait {
+                span: meta.path.get_ident().unwrap().span(),
+                idents,
+            });
+            Ok(())
+        } else if meta.path.is_ident("export") {
+            if self.export.is_some() {
+                return Err(meta.error("duplicate export argument"));
+            }
+            self.export = Some(meta.path.get_ident().unwrap().span());
+            Ok(())
+        } else if meta.path.is_ident("by") {
+            if self.by.is_some() {
+                return Err(meta.error("duplicate by argument"));
+            }
+            self.by = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("desc") {
+            if self.desc {
+                return Err(meta.error("duplicate desc argument"));
+            }
+            if self.order == Some(Order::Bytes) {
+                return Err(meta.error(BYTES_DESC));
+            }
+            self.desc = true;
+            Ok(())
+        } else if meta.path.is_ident("order") {
+            if self.order.is_some() {
+                return Err(meta.error("duplicate order argument"));
+            }
+            let lit: LitStr = meta.value()?.parse()?;
+            self.order = Some(match lit.value().as_str() {
+                "natural" => Order::Natural,
+                "ascii" => Order::Ascii,
+                "bytes" if self.desc => return Err(Error::new(lit.span(), BYTES_DESC)),
+                "bytes" => Order::Bytes,
+                _ => {
+                    let msg = "expected \"natural\", \"ascii\", or \"bytes\"";
+                    return Err(Error::new(lit.span(), msg));
+                }
+            });
+            Ok(())
+        } else if meta.path.is_ident("numbers") {
+            if self.numbers.is_some() {
+                return Err(meta.error("duplicate numbers argument"));
+            }
+            let lit: LitStr = meta.value()?.parse()?;
+            self.numbers = Some(match lit.value().as_str() {
+                "natural" => Numbers::Natural,
+                "lexical" => Numbers::Lexical,
+                _ => {
+                    let msg = "expected \"natural\" or \"lexical\"";
+                    return Err(Error::new(lit.span(), msg));
+                }
+            });
+            Ok(())
+        } else if meta.path.is_ident("underscores") {
+            if self.underscores.is_some() {
+                return Err(meta.error("duplicate underscores argument"));
+            }
+            let lit: LitStr = meta.value()?.parse()?;
+            self.underscores = Some(match lit.value().as_str() {
+                "first" => UnderscoreOrder::First,
+                "last" => UnderscoreOrder::Last,
+                _ => {
+                    let msg = "expected \"first\" or \"last\"";
+                    return Err(Error::new(lit.span(), msg));
+                }
+            });
+            Ok(())
+        } else if meta.path.is_ident("or_patterns") {
+            if self.or_patterns.is_some() {
+                return Err(meta.error("duplicate or_patterns argument"));
+            }
+            self.or_patterns = Some(meta.path.get_ident().unwrap().span());
+            Ok(())
+        } else if meta.path.is_ident("unique") {
+            if self.unique {
+                return Err(meta.error("duplicate unique argument"));
+            }
+            self.unique = true;
+            Ok(())
+        } else {
+            Err(meta.error("unsupported remain::sorted argument"))
+        }
+    }
+}
@@ -42,7 +42,7 @@ impl Ord for Atom<'_> {
     }
 }
 
-fn cmp_numeric(l: &str, r: &str) -> Ordering {
+pub fn cmp_numeric(l: &str, r: &str) -> Ordering {
     // Trim leading zeros.
     let l = l.trim_start_matches('0');
     let r = r.trim_start_matches('0');
@@ -67,16 +67,20 @@ fn cmp_ignore_case(l: &str, r: &str) -> Ordering {
     l.len().cmp(&r.len())
 }
 
-pub fn iter_atoms(string: &str) -> AtomIter {
+pub fn iter_atoms(string: &str, numbers: bool) -> AtomIter {
     AtomIter {
         bytes: string.as_bytes(),
         offset: 0,
+        numbers,
     }
 }
 
 pub struct AtomIter<'a> {
     bytes: &'a [u8],
     offset: usize,
+    /// Whether a sequence of digits forms its own atom. If not, digits are
+    /// treated like any other character.
+    numbers: bool,
 }
 
 impl<'a> Iterator for AtomIter<'a> {
@@ -106,7 +110,7 @@ impl<'a> Iterator for AtomIter<'a> {
 
                 Some(Atom::Underscore(n))
             }
-            b'0'..=b'9' => {
+            b'0'..=b'9' if self.numbers => {
                 let start = self.offset;
 
                 self.offset += 1;
@@ -127,7 +131,8 @@ impl<'a> Iterator for AtomIter<'a> {
                 self.offset += 1;
                 while self.offset < self.bytes.len() {
                     match self.bytes[self.offset] {
-                        b'_' | b'0'..=b'9' => break,
+                        b'_' => break,
+                        b'0'..=b'9' if self.numbers => break,
                         _ => self.offset += 1,
                     }
                 }
@@ -1,46 +1,422 @@
-use quote::quote;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
 use std::cmp::Ordering;
-use syn::{Arm, Attribute, Ident, Result, Variant};
-use syn::{Error, Field, Pat, PatIdent};
+use std::mem::{self, Discriminant};
+use syn::{Arm, Attribute, Ident, ImplItem, Item, ItemMacro, Result, TraitItem, UseTree, Variant};
+use syn::{
+    Error, Expr, ExprLit, ExprUnary, Field, FieldPat, FieldValue, Lit, LitInt, Member, Pat,
+    PatRange, RangeLimits, UnOp,
+};
 
-use crate::compare::{cmp, Path, UnderscoreOrder};
-use crate::format;
+use crate::args::{Args, LikeTrait};
+use crate::compare::{
+    cmp, overlaps, Mode, Numbers, Order, Path, Range, Segment, Tuple, UnderscoreOrder,
+};
+use crate::export;
+use crate::format::{self, Position};
 use crate::parse::Input::{self, *};
 
-pub fn sorted(input: &mut Input) -> Result<()> {
-    let paths = match input {
-        Enum(item) => collect_paths(&mut item.variants)?,
-        Struct(item) => collect_paths(&mut item.fields)?,
-        Match(expr) | Let(expr) => collect_paths(&mut expr.arms)?,
+pub fn sorted(input: &mut Input, args: &Args) -> Result<TokenStream> {
+    let alternatives = match (&args.or_patterns, &*input) {
+        (None, _) => Ok(()),
+        (Some(_), Match(expr) | Let(expr)) => check_alternatives(input, &expr.arms, args),
+        (Some(span), _) => {
+            let msg = "or_patterns is only supported on match expressions";
+            return Err(Error::new(*span, msg));
+        }
+    };
+
+    let arms: Vec<ArmInfo> = match &*input {
+        Match(expr) | Let(expr) => expr
+            .arms
+            .iter()
+            .filter(|arm| !arm.attrs.iter().any(is_unsorted_attr))
+            .map(ArmInfo::new)
+            .collect(),
+        Enum(_) | Struct(_) | Impl(_) | Trait(_) | Mod(_) | Use(_) | Const(_) | Static(_)
+        | StructExpr(_) | StructPat(_) => Vec::new(),
     };
 
-    let mode = UnderscoreOrder::First;
-    if find_misordered(&paths, mode).is_none() {
+    // The items of a module are sorted separately for each kind of item.
+    let groups = match input {
+        Enum(item) => vec![collect_paths(&mut item.variants)?],
+        Struct(item) => vec![collect_paths(&mut item.fields)?],
+        Match(expr) | Let(expr) => vec![collect_paths(&mut expr.arms)?],
+        Impl(item) => vec![collect_paths(&mut item.items)?],
+        Trait(item) => vec![collect_paths(&mut item.items)?],
+        Mod(item) => match &mut item.content {
+            Some((_brace, items)) => collect_groups(items)?,
+            None => Vec::new(),
+        },
+        Use(item) => {
+            let mut groups = Vec::new();
+            collect_use_groups(&item.tree, &mut groups);
+            groups
+        }
+        Const(item) => vec![collect_elements(&item.expr)?],
+        Static(item) => vec![collect_elements(&item.expr)?],
+        StructExpr(expr) => vec![collect_paths(&mut expr.fields)?],
+        StructPat(pat) => vec![collect_paths(&mut pat.fields)?],
+    };
+
+    if let Some(span) = args.export {
+        let Enum(item) = input else {
+            let msg = "export is only supported on enums";
+            return Err(Error::new(span, msg));
+        };
+        return export::order_const(item, span);
+    }
+
+    let mut extra = TokenStream::new();
+    if let Some(by) = &args.by {
+        if !matches!(input, Match(_) | Let(_)) {
+            let msg = "by is only supported on match expressions";
+            return Err(Error::new_spanned(by, msg));
+        }
+        // The order declared by the enum leaves nothing for these to adjust.
+        let conflicting = [
+            ("like_trait", args.like_trait.is_some()),
+            ("desc", args.desc),
+            ("order", args.order.is_some()),
+            ("numbers", args.numbers.is_some()),
+            ("underscores", args.underscores.is_some()),
+            ("or_patterns", args.or_patterns.is_some()),
+        ];
+        if let Some((name, _)) = conflicting.iter().find(|(_, present)| *present) {
+            let msg = format!("{} cannot be combined with by", name);
+            return Err(Error::new_spanned(by, msg));
+        }
+        extra = export::check_against(&groups[0], by)?;
+    }
+
+    let mut result = Ok(());
+    for paths in &groups {
+        if args.by.is_none() {
+            merge(&mut result, check_order(input, paths, args));
+        }
+        merge(&mut result, check_overlaps(paths, &arms, args));
+        merge(&mut result, check_guards(paths, &arms, args));
+        if args.unique {
+            merge(&mut result, check_duplicates(paths, &arms, args));
+        }
+    }
+    merge(&mut result, alternatives);
+    result.map(|()| extra)
+}
+
+fn merge(result: &mut Result<()>, other: Result<()>) {
+    if let Err(other) = other {
+        match result {
+            Ok(()) => *result = Err(other),
+            Err(err) => err.combine(other),
+        }
+    }
+}
+
+fn mode(args: &Args, underscores: UnderscoreOrder) -> Mode {
+    Mode {
+        underscores,
+        descending: args.desc,
+        order: args.order.unwrap_or(Order::Natural),
+        numbers: args.numbers.unwrap_or(Numbers::Natural),
+    }
+}
+
+fn check_order(input: &Input, paths: &[Path], args: &Args) -> Result<()> {
+    if let Some(like_trait) = &args.like_trait {
+        if !matches!(input, Impl(item) if item.trait_.is_some()) {
+            let msg = "like_trait is only supported on trait impls";
+            return Err(Error::new(like_trait.span, msg));
+        }
+        let positions = paths
+            .iter()
+            .map(|path| position_in_trait(path, like_trait))
+            .collect::<Result<Vec<usize>>>()?;
+        let misordered = find_misordered(&positions, usize::cmp);
+        return report(
+            paths,
+            &positions,
+            &misordered,
+            usize::cmp,
+            Position::Before,
+            None,
+        );
+    }
+
+    // Describe misplaced elements relative to the element they should follow
+    // in descending order, and relative to the one they should precede in
+    // ascending order.
+    let position = if args.desc {
+        Position::After
+    } else {
+        Position::Before
+    };
+
+    if let Some(underscores) = args.underscores {
+        let mode = mode(args, underscores);
+        let cmp = |lhs: &Path, rhs: &Path| cmp(lhs, rhs, mode);
+        let misordered = find_misordered(paths, cmp);
+        return report(paths, paths, &misordered, cmp, position, Some(underscores));
+    }
+
+    let mode_first = mode(args, UnderscoreOrder::First);
+    let cmp_first = |lhs: &Path, rhs: &Path| cmp(lhs, rhs, mode_first);
+    let misordered_first = find_misordered(paths, cmp_first);
+    if misordered_first.is_empty() {
         return Ok(());
     }
 
-    let mode = UnderscoreOrder::Last;
-    let Some(wrong) = find_misordered(&paths, mode) else {
+    let mode_last = mode(args, UnderscoreOrder::Last);
+    let cmp_last = |lhs: &Path, rhs: &Path| cmp(lhs, rhs, mode_last);
+    let misordered_last = find_misordered(paths, cmp_last);
+    if misordered_last.is_empty() {
         return Ok(());
-    };
+    }
+
+    // Report whichever convention leaves fewer elements out of place.
+    if misordered_first.len() < misordered_last.len() {
+        report(paths, paths, &misordered_first, cmp_first, position, None)
+    } else {
+        report(paths, paths, &misordered_last, cmp_last, position, None)
+    }
+}
+
+// The cases within each or-pattern, like `A | B | C`, must be sorted among
+// themselves.
+fn check_alternatives(input: &Input, arms: &[Arm], args: &Args) -> Result<()> {
+    let mut result = Ok(());
+
+    for arm in arms {
+        if arm.attrs.iter().any(is_unsorted_attr) {
+            continue;
+        }
+        let pat = match &arm.pat {
+            Pat::Guard(pat) => &*pat.pat,
+            pat => pat,
+        };
+        if let Pat::Or(pat) = pat {
+            let paths = pat
+                .cases
+                .iter()
+                .map(pat_to_path)
+                .collect::<Result<Vec<Path>>>()?;
+            merge(&mut result, check_order(input, &paths, args));
+        }
+    }
+
+    result
+}
+
+// Adjacent range patterns that match some of the same values. A guarded arm
+// may fall through to a later arm matching the same values.
+fn check_overlaps(paths: &[Path], arms: &[ArmInfo], args: &Args) -> Result<()> {
+    let mode = mode(args, UnderscoreOrder::First);
+    let mut result = Ok(());
+
+    for (i, pair) in paths.windows(2).enumerate() {
+        if arms.get(i).is_some_and(|arm| arm.guarded) {
+            continue;
+        }
+        if let ([prev], [next]) = (&*pair[0].segments, &*pair[1].segments) {
+            if overlaps(prev, next, mode) {
+                merge(&mut result, Err(format::overlap_error(next, prev)));
+            }
+        }
+    }
 
-    let lesser = &paths[wrong];
-    let correct_pos = match paths[..wrong - 1].binary_search_by(|probe| cmp(probe, lesser, mode)) {
-        Err(correct_pos) => correct_pos,
-        Ok(equal_to) => equal_to + 1,
+    result
+}
+
+// What a match arm matches beyond its sort key.
+struct ArmInfo {
+    guarded: bool,
+    // Whether the arm matches every value with its key, like `E::A(..)` does.
+    catch_all: bool,
+    // The whole pattern, including any guard.
+    pat: String,
+}
+
+impl ArmInfo {
+    fn new(arm: &Arm) -> Self {
+        match &arm.pat {
+            Pat::Guard(pat) => ArmInfo {
+                guarded: true,
+                catch_all: is_catch_all(&pat.pat),
+                pat: pat.to_token_stream().to_string(),
+            },
+            pat => ArmInfo {
+                guarded: false,
+                catch_all: is_catch_all(pat),
+                pat: pat.to_token_stream().to_string(),
+            },
+        }
+    }
+}
+
+// Guarded arms must precede an unguarded catch-all arm with the same key,
+// which would otherwise shadow them.
+fn check_guards(paths: &[Path], arms: &[ArmInfo], args: &Args) -> Result<()> {
+    let mode = mode(args, args.underscores.unwrap_or(UnderscoreOrder::First));
+    let mut result = Ok(());
+
+    for (i, path) in paths.iter().enumerate() {
+        if !arms.get(i).is_some_and(|arm| arm.guarded) {
+            continue;
+        }
+        if let Some(unguarded) = find_shadowing(paths, arms, i, mode) {
+            merge(&mut result, Err(format::guard_error(path, unguarded)));
+        }
+    }
+
+    result
+}
+
+// Elements whose key compares equal to that of an earlier element. A match arm
+// is only a duplicate if an earlier arm has the identical pattern, or matches
+// everything with the same key so that this arm is unreachable.
+fn check_duplicates(paths: &[Path], arms: &[ArmInfo], args: &Args) -> Result<()> {
+    let mode = mode(args, args.underscores.unwrap_or(UnderscoreOrder::First));
+    let mut result = Ok(());
+
+    for (i, path) in paths.iter().enumerate() {
+        let first = match arms.get(i) {
+            Some(arm) => paths[..i]
+                .iter()
+                .zip(arms)
+                .find(|(_, earlier)| earlier.pat == arm.pat)
+                .map(|(earlier, _)| earlier)
+                // A shadowed guarded arm is reported by check_guards.
+                .or_else(|| {
+                    (!arm.guarded)
+                        .then(|| find_shadowing(paths, arms, i, mode))
+                        .flatten()
+                }),
+            None => find_shadowing(paths, arms, i, mode),
+        };
+        if let Some(first) = first {
+            merge(&mut result, Err(format::duplicate_error(path, first)));
+        }
+    }
+
+    result
+}
+
+// The first element before index i that has the same key and matches every
+// value with that key. Only match arms can fall short of that.
+fn find_shadowing<'a>(
+    paths: &'a [Path],
+    arms: &[ArmInfo],
+    i: usize,
+    mode: Mode,
+) -> Option<&'a Path> {
+    paths[..i]
+        .iter()
+        .enumerate()
+        .find(|(j, earlier)| {
+            let shadows = arms
+                .get(*j)
+                .map_or(true, |arm| !arm.guarded && arm.catch_all);
+            shadows && cmp(earlier, &paths[i], mode) == Ordering::Equal
+        })
+        .map(|(_, earlier)| earlier)
+}
+
+fn position_in_trait(path: &Path, like_trait: &LikeTrait) -> Result<usize> {
+    let name = path.to_string();
+    let Some(position) = like_trait
+        .idents
+        .iter()
+        .position(|expected| *expected == name)
+    else {
+        let msg = format!("{} is not listed in like_trait", name);
+        return Err(Error::new_spanned(&path.segments[0], msg));
     };
-    let greater = &paths[correct_pos];
-    Err(format::error(lesser, greater))
+    Ok(position)
 }
 
-fn find_misordered(paths: &[Path], mode: UnderscoreOrder) -> Option<usize> {
-    for i in 1..paths.len() {
-        if cmp(&paths[i], &paths[i - 1], mode) == Ordering::Less {
-            return Some(i);
+// Indices of the elements that are not part of a longest sorted subsequence.
+// Among equally long subsequences, the one keeping the earliest elements wins,
+// so that the elements reported as out of place are the ones appearing later.
+fn find_misordered<T>(keys: &[T], cmp: impl Fn(&T, &T) -> Ordering) -> Vec<usize> {
+    // Length of the longest sorted subsequence starting at each index.
+    let mut len = vec![0; keys.len()];
+
+    // Greatest first element among sorted subsequences of length k + 1 seen
+    // so far, scanning from the end.
+    let mut heads: Vec<&T> = Vec::new();
+
+    for (i, key) in keys.iter().enumerate().rev() {
+        let k = heads.partition_point(|head| cmp(head, key) != Ordering::Less);
+        len[i] = k + 1;
+        if k == heads.len() {
+            heads.push(key);
+        } else {
+            heads[k] = key;
         }
     }
 
-    None
+    let mut remaining = heads.len();
+    let mut prev: Option<&T> = None;
+    let mut misordered = Vec::new();
+
+    for (i, key) in keys.iter().enumerate() {
+        let in_order = prev.map_or(true, |prev| cmp(prev, key) != Ordering::Greater);
+        if remaining > 0 && len[i] == remaining && in_order {
+            prev = Some(key);
+            remaining -= 1;
+        } else {
+            misordered.push(i);
+        }
+    }
+
+    misordered
+}
+
+fn report<T>(
+    paths: &[Path],
+    keys: &[T],
+    misordered: &[usize],
+    cmp: impl Fn(&T, &T) -> Ordering,
+    position: Position,
+    underscores: Option<UnderscoreOrder>,
+) -> Result<()> {
+    if misordered.is_empty() {
+        return Ok(());
+    }
+
+    let sorted: Vec<usize> = (0..keys.len())
+        .filter(|i| misordered.binary_search(i).is_err())
+        .collect();
+
+    let mut errors = misordered.iter().map(|&wrong| {
+        let correct_pos =
+            sorted.partition_point(|&probe| cmp(&keys[probe], &keys[wrong]) != Ordering::Greater);
+        let before = sorted.get(correct_pos);
+        let after = correct_pos.checked_sub(1).map(|i| &sorted[i]);
+        match (position, before, after) {
+            (Position::Before, Some(&greater), _) | (Position::After, Some(&greater), None) => {
+                let greater = &paths[greater];
+                format::error(&paths[wrong], Position::Before, greater, underscores)
+            }
+            (_, _, Some(&lesser)) => {
+                let lesser = &paths[lesser];
+                format::error(&paths[wrong], Position::After, lesser, underscores)
+            }
+            (_, None, None) => unreachable!(),
+        }
+    });
+
+    let mut combined = errors.next().unwrap();
+    for error in errors {
+        combined.combine(error);
+    }
+
+    let mut expected: Vec<usize> = (0..keys.len()).collect();
+    expected.sort_by(|&lhs, &rhs| cmp(&keys[lhs], &keys[rhs]));
+    let expected: Vec<&Path> = expected.iter().map(|&i| &paths[i]).collect();
+    combined.combine(format::expected_order(&paths[0], &expected));
+
+    Err(combined)
 }
 
 fn collect_paths<'a, I, P>(iter: I) -> Result<Vec<Path>>
@@ -50,7 +426,7 @@ where
 {
     iter.into_iter()
         .filter_map(|item| {
-            if remove_unsorted_attr(item.attrs()) {
+            if item.attrs().is_some_and(remove_unsorted_attr) || item.is_unnamed() {
                 None
             } else {
                 Some(item.to_path())
@@ -59,11 +435,28 @@ where
         .collect()
 }
 
+// Unnamed items, like impl blocks, are not part of any group.
+fn collect_groups(items: &mut [Item]) -> Result<Vec<Vec<Path>>> {
+    let mut groups: Vec<(Discriminant<Item>, Vec<Path>)> = Vec::new();
+
+    for item in items {
+        if item.attrs().is_some_and(remove_unsorted_attr) || item.is_unnamed() {
+            continue;
+        }
+        let path = item.to_path()?;
+        let kind = mem::discriminant(&*item);
+        match groups.iter_mut().find(|(group, _paths)| *group == kind) {
+            Some((_kind, paths)) => paths.push(path),
+            None => groups.push((kind, vec![path])),
+        }
+    }
+
+    Ok(groups.into_iter().map(|(_kind, paths)| paths).collect())
+}
+
 fn remove_unsorted_attr(attrs: &mut Vec<Attribute>) -> bool {
     for i in 0..attrs.len() {
-        let path = &attrs[i].path();
-        let path = quote!(#path).to_string();
-        if path == "unsorted" || path == "remain :: unsorted" {
+        if is_unsorted_attr(&attrs[i]) {
             attrs.remove(i);
             return true;
         }
@@ -72,64 +465,407 @@ fn remove_unsorted_attr(attrs: &mut Vec<Attribute>) -> bool {
     false
 }
 
+fn is_unsorted_attr(attr: &Attribute) -> bool {
+    let path = attr.path();
+    let path = quote!(#path).to_string();
+    path == "unsorted" || path == "remain :: unsorted"
+}
+
 trait Sortable {
     fn to_path(&self) -> Result<Path>;
-    fn attrs(&mut self) -> &mut Vec<Attribute>;
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>>;
+    // Unnamed elements, like macro invocations, may go anywhere.
+    fn is_unnamed(&self) -> bool {
+        false
+    }
 }
 
 impl Sortable for Variant {
     fn to_path(&self) -> Result<Path> {
         Ok(Path {
-            segments: vec![self.ident.clone()],
+            segments: vec![Segment::Ident(self.ident.clone())],
         })
     }
-    fn attrs(&mut self) -> &mut Vec<Attribute> {
-        &mut self.attrs
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        Some(&mut self.attrs)
     }
 }
 
 impl Sortable for Field {
     fn to_path(&self) -> Result<Path> {
         Ok(Path {
-            segments: vec![self.ident.clone().expect("must be named field")],
+            segments: vec![Segment::Ident(
+                self.ident.clone().expect("must be named field"),
+            )],
         })
     }
-    fn attrs(&mut self) -> &mut Vec<Attribute> {
-        &mut self.attrs
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        Some(&mut self.attrs)
+    }
+}
+
+impl Sortable for FieldValue {
+    fn to_path(&self) -> Result<Path> {
+        Ok(member_to_path(&self.member))
+    }
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        Some(&mut self.attrs)
+    }
+}
+
+impl Sortable for FieldPat {
+    fn to_path(&self) -> Result<Path> {
+        Ok(member_to_path(&self.member))
+    }
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        Some(&mut self.attrs)
+    }
+}
+
+fn member_to_path(member: &Member) -> Path {
+    let segment = match member {
+        Member::Named(ident) => Segment::Ident(ident.clone()),
+        Member::Unnamed(index) => {
+            let lit = LitInt::new(&index.index.to_string(), index.span);
+            Segment::Lit(Lit::Int(lit))
+        }
+    };
+
+    Path {
+        segments: vec![segment],
     }
 }
 
 impl Sortable for Arm {
     fn to_path(&self) -> Result<Path> {
+        pat_to_path(&self.pat)
+    }
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        Some(&mut self.attrs)
+    }
+}
+
+fn pat_to_path(pat: &Pat) -> Result<Path> {
+    let segments = match pat {
+        Pat::Ident(pat) => match &pat.subpat {
+            // Sort `binding @ Pattern` by its pattern.
+            Some((_at, subpat)) => return pat_to_path(subpat),
+            None if pat.by_ref.is_some() || pat.mutability.is_some() => {
+                vec![Segment::Binding(pat.clone())]
+            }
+            None => vec![Segment::Ident(pat.ident.clone())],
+        },
+        Pat::Lit(pat) if is_supported_lit(&pat.lit) => vec![Segment::Lit(pat.lit.clone())],
+        Pat::Range(pat) if is_supported_range(pat) => vec![Segment::Range(Range {
+            pat: pat.clone(),
+            start: pat.start.as_deref().map(range_bound),
+            end: pat.end.as_deref().map(range_bound),
+            inclusive: matches!(pat.limits, RangeLimits::Closed(_)),
+        })],
+        Pat::Path(pat) => idents_of_path(&pat.path),
+        Pat::Struct(pat) => idents_of_path(&pat.path),
+        Pat::TupleStruct(pat) => idents_of_path(&pat.path),
+        // Only valid within a tuple, where it sorts last like `_`.
+        Pat::Rest(pat) => vec![Segment::Rest(pat.clone())],
+        Pat::Tuple(pat) => vec![Segment::Tuple(Tuple {
+            pat: pat.clone(),
+            elems: pat.elems.iter().map(pat_to_path).collect::<Result<_>>()?,
+        })],
+        // Sort by the pattern, ignoring any `if` guard.
+        Pat::Guard(pat) => return pat_to_path(&pat.pat),
         // Sort by just the first pat.
-        let pat = match &self.pat {
-            Pat::Or(pat) => pat.cases.iter().next().expect("at least one pat"),
-            _ => &self.pat,
+        Pat::Or(pat) => return pat_to_path(pat.cases.iter().next().expect("at least one pat")),
+        Pat::Reference(pat) => return pat_to_path(&pat.pat),
+        Pat::Paren(pat) => return pat_to_path(&pat.pat),
+        Pat::Wild(pat) => vec![Segment::Ident(Ident::from(pat.underscore_token))],
+        other => {
+            let msg = "unsupported by #[remain::sorted]";
+            return Err(Error::new_spanned(other, msg));
+        }
+    };
+
+    Ok(Path { segments })
+}
+
+impl Sortable for ImplItem {
+    fn to_path(&self) -> Result<Path> {
+        let ident = match self {
+            ImplItem::Const(item) => &item.ident,
+            ImplItem::Fn(item) => &item.sig.ident,
+            ImplItem::Type(item) => &item.ident,
+            other => {
+                let msg = "unsupported by #[remain::sorted]";
+                return Err(Error::new_spanned(other, msg));
+            }
         };
 
-        let segments = match pat {
-            Pat::Ident(pat) if is_just_ident(pat) => vec![pat.ident.clone()],
-            Pat::Path(pat) => idents_of_path(&pat.path),
-            Pat::Struct(pat) => idents_of_path(&pat.path),
-            Pat::TupleStruct(pat) => idents_of_path(&pat.path),
-            Pat::Wild(pat) => vec![Ident::from(pat.underscore_token)],
+        Ok(Path {
+            segments: vec![Segment::Ident(ident.clone())],
+        })
+    }
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        match self {
+            ImplItem::Const(item) => Some(&mut item.attrs),
+            ImplItem::Fn(item) => Some(&mut item.attrs),
+            ImplItem::Type(item) => Some(&mut item.attrs),
+            ImplItem::Macro(item) => Some(&mut item.attrs),
+            _ => None,
+        }
+    }
+    fn is_unnamed(&self) -> bool {
+        matches!(self, ImplItem::Macro(_))
+    }
+}
+
+impl Sortable for TraitItem {
+    fn to_path(&self) -> Result<Path> {
+        let ident = match self {
+            TraitItem::Const(item) => &item.ident,
+            TraitItem::Fn(item) => &item.sig.ident,
+            TraitItem::Type(item) => &item.ident,
             other => {
                 let msg = "unsupported by #[remain::sorted]";
                 return Err(Error::new_spanned(other, msg));
             }
         };
 
-        Ok(Path { segments })
+        Ok(Path {
+            segments: vec![Segment::Ident(ident.clone())],
+        })
     }
-    fn attrs(&mut self) -> &mut Vec<Attribute> {
-        &mut self.attrs
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        match self {
+            TraitItem::Const(item) => Some(&mut item.attrs),
+            TraitItem::Fn(item) => Some(&mut item.attrs),
+            TraitItem::Type(item) => Some(&mut item.attrs),
+            TraitItem::Macro(item) => Some(&mut item.attrs),
+            _ => None,
+        }
+    }
+    fn is_unnamed(&self) -> bool {
+        matches!(self, TraitItem::Macro(_))
     }
 }
 
-fn idents_of_path(path: &syn::Path) -> Vec<Ident> {
-    path.segments.iter().map(|seg| seg.ident.clone()).collect()
+impl Sortable for Item {
+    fn to_path(&self) -> Result<Path> {
+        let ident = match self {
+            Item::Const(item) => &item.ident,
+            Item::Enum(item) => &item.ident,
+            Item::ExternCrate(item) => &item.ident,
+            Item::Fn(item) => &item.sig.ident,
+            Item::Macro(ItemMacro {
+                ident: Some(ident), ..
+            }) => ident,
+            Item::Mod(item) => &item.ident,
+            Item::Static(item) => &item.ident,
+            Item::Struct(item) => &item.ident,
+            Item::Trait(item) => &item.ident,
+            Item::TraitAlias(item) => &item.ident,
+            Item::Type(item) => &item.ident,
+            Item::Union(item) => &item.ident,
+            Item::Use(item) => return Ok(use_tree_to_path(&item.tree)),
+            other => {
+                let msg = "unsupported by #[remain::sorted]";
+                return Err(Error::new_spanned(other, msg));
+            }
+        };
+
+        Ok(Path {
+            segments: vec![Segment::Ident(ident.clone())],
+        })
+    }
+    fn attrs(&mut self) -> Option<&mut Vec<Attribute>> {
+        match self {
+            Item::Const(item) => Some(&mut item.attrs),
+            Item::Enum(item) => Some(&mut item.attrs),
+            Item::ExternCrate(item) => Some(&mut item.attrs),
+            Item::Fn(item) => Some(&mut item.attrs),
+            Item::ForeignMod(item) => Some(&mut item.attrs),
+            Item::Impl(item) => Some(&mut item.attrs),
+            Item::Macro(item) => Some(&mut item.attrs),
+            Item::Mod(item) => Some(&mut item.attrs),
+            Item::Static(item) => Some(&mut item.attrs),
+            Item::Struct(item) => Some(&mut item.attrs),
+            Item::Trait(item) => Some(&mut item.attrs),
+            Item::TraitAlias(item) => Some(&mut item.attrs),
+            Item::Type(item) => Some(&mut item.attrs),
+            Item::Union(item) => Some(&mut item.attrs),
+            Item::Use(item) => Some(&mut item.attrs),
+            _ => None,
+        }
+    }
+    fn is_unnamed(&self) -> bool {
+        match self {
+            Item::Impl(_) | Item::ForeignMod(_) => true,
+            Item::Macro(item) => item.ident.is_none(),
+            _ => false,
+        }
+    }
+}
+
+// The elements of an array or slice literal, like `&["a", "b"]`.
+fn collect_elements(expr: &Expr) -> Result<Vec<Path>> {
+    match expr {
+        Expr::Reference(expr) => collect_elements(&expr.expr),
+        Expr::Array(expr) => expr.elems.iter().map(expr_to_path).collect(),
+        other => {
+            let msg = "expected array or slice literal";
+            Err(Error::new_spanned(other, msg))
+        }
+    }
+}
+
+fn expr_to_path(expr: &Expr) -> Result<Path> {
+    let lit = match expr {
+        Expr::Lit(expr) if is_supported_lit(&expr.lit) => Some(expr.lit.clone()),
+        Expr::Unary(expr) => negative_int(expr),
+        // Sort tuples by their leading key.
+        Expr::Tuple(expr) if !expr.elems.is_empty() => return expr_to_path(&expr.elems[0]),
+        _ => None,
+    };
+
+    let Some(lit) = lit else {
+        let msg = "unsupported by #[remain::sorted]";
+        return Err(Error::new_spanned(expr, msg));
+    };
+
+    Ok(Path {
+        segments: vec![Segment::Lit(lit)],
+    })
+}
+
+// In expressions, unlike patterns, `-1` is a negation applied to a literal.
+fn negative_int(expr: &ExprUnary) -> Option<Lit> {
+    let (
+        UnOp::Neg(_),
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }),
+    ) = (&expr.op, &*expr.expr)
+    else {
+        return None;
+    };
+    Some(Lit::Int(LitInt::new(&format!("-{}", lit), lit.span())))
+}
+
+// Every brace-delimited group in a use tree is sorted separately. The `self`
+// import and glob imports may go anywhere within a group.
+fn collect_use_groups(tree: &UseTree, groups: &mut Vec<Vec<Path>>) {
+    match tree {
+        UseTree::Path(path) => collect_use_groups(&path.tree, groups),
+        UseTree::Group(group) => {
+            let paths = group
+                .items
+                .iter()
+                .filter(|tree| match tree {
+                    UseTree::Name(name) => name.ident != "self",
+                    UseTree::Rename(rename) => rename.ident != "self",
+                    UseTree::Glob(_) => false,
+                    UseTree::Path(_) | UseTree::Group(_) => true,
+                })
+                .map(use_tree_to_path)
+                .collect();
+            groups.push(paths);
+            for tree in &group.items {
+                collect_use_groups(tree, groups);
+            }
+        }
+        UseTree::Name(_) | UseTree::Rename(_) | UseTree::Glob(_) => {}
+    }
+}
+
+// Sort `use` declarations by their path, up to any group or glob.
+fn use_tree_to_path(mut tree: &UseTree) -> Path {
+    let mut segments = Vec::new();
+    loop {
+        match tree {
+            UseTree::Path(path) => {
+                segments.push(Segment::Ident(path.ident.clone()));
+                tree = &path.tree;
+            }
+            UseTree::Name(name) => {
+                segments.push(Segment::Ident(name.ident.clone()));
+                break;
+            }
+            UseTree::Rename(rename) => {
+                segments.push(Segment::Ident(rename.ident.clone()));
+                break;
+            }
+            UseTree::Glob(_) | UseTree::Group(_) => break,
+        }
+    }
+    Path { segments }
+}
+
+fn idents_of_path(path: &syn::Path) -> Vec<Segment> {
+    path.segments
+        .iter()
+        .map(|seg| Segment::Ident(seg.ident.clone()))
+        .collect()
 }
 
-fn is_just_ident(pat: &PatIdent) -> bool {
-    pat.by_ref.is_none() && pat.mutability.is_none() && pat.subpat.is_none()
+// Whether a pattern matches every value with its sort key. Subpatterns that
+// are part of the key, like tuple elements, must be catch-alls themselves, and
+// others must be irrefutable.
+fn is_catch_all(pat: &Pat) -> bool {
+    match pat {
+        Pat::Lit(_) | Pat::Path(_) | Pat::Range(_) | Pat::Rest(_) | Pat::Wild(_) => true,
+        Pat::Ident(pat) => pat
+            .subpat
+            .as_ref()
+            .map_or(true, |(_at, subpat)| is_catch_all(subpat)),
+        Pat::Or(pat) => pat.cases.iter().next().is_some_and(is_catch_all),
+        Pat::Paren(pat) => is_catch_all(&pat.pat),
+        Pat::Reference(pat) => is_catch_all(&pat.pat),
+        Pat::Struct(pat) => pat.fields.iter().all(|field| is_irrefutable(&field.pat)),
+        Pat::Tuple(pat) => pat.elems.iter().all(is_catch_all),
+        Pat::TupleStruct(pat) => pat.elems.iter().all(is_irrefutable),
+        _ => false,
+    }
+}
+
+// A bare identifier may be a unit variant or const like `None` rather than a
+// binding, so only identifiers that are surely bindings count.
+fn is_irrefutable(pat: &Pat) -> bool {
+    match pat {
+        Pat::Rest(_) | Pat::Wild(_) => true,
+        Pat::Ident(pat) => match &pat.subpat {
+            Some((_at, subpat)) => is_irrefutable(subpat),
+            None => pat.by_ref.is_some() || pat.mutability.is_some(),
+        },
+        Pat::Paren(pat) => is_irrefutable(&pat.pat),
+        Pat::Tuple(pat) => pat.elems.iter().all(is_irrefutable),
+        Pat::Type(pat) => is_irrefutable(&pat.pat),
+        _ => false,
+    }
+}
+
+fn is_supported_lit(lit: &Lit) -> bool {
+    matches!(
+        lit,
+        Lit::Str(_)
+            | Lit::ByteStr(_)
+            | Lit::CStr(_)
+            | Lit::Byte(_)
+            | Lit::Char(_)
+            | Lit::Int(_)
+            | Lit::Bool(_)
+    )
+}
+
+fn is_supported_range(pat: &PatRange) -> bool {
+    let is_supported_bound = |bound: &Option<Box<Expr>>| match bound.as_deref() {
+        None => true,
+        Some(Expr::Lit(expr)) => matches!(expr.lit, Lit::Byte(_) | Lit::Char(_) | Lit::Int(_)),
+        Some(_) => false,
+    };
+    is_supported_bound(&pat.start) && is_supported_bound(&pat.end)
+}
+
+fn range_bound(expr: &Expr) -> Lit {
+    match expr {
+        Expr::Lit(expr) => expr.lit.clone(),
+        _ => unreachable!("checked by is_supported_range"),
+    }
 }
@@ -1,7 +1,10 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::ToTokens;
 use std::cmp::Ordering;
+use syn::spanned::Spanned;
+use syn::{Lit, PatIdent, PatRange, PatRest, PatTuple};
 
-use crate::atom::iter_atoms;
+use crate::atom::{cmp_numeric, iter_atoms};
 
 #[derive(Copy, Clone, PartialEq)]
 pub enum UnderscoreOrder {
@@ -9,39 +12,201 @@ pub enum UnderscoreOrder {
     Last,
 }
 
+#[derive(Copy, Clone, PartialEq)]
+pub enum Order {
+    /// Case-insensitive, with case only breaking ties.
+    Natural,
+    /// Case-sensitive, so that uppercase sorts before lowercase.
+    Ascii,
+    /// Byte-wise, exactly like `Ord for str`.
+    Bytes,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Numbers {
+    /// Sequences of digits compare by numeric value, so that `E9 < E10`.
+    Natural,
+    /// Digits compare like any other character, so that `E10 < E9`.
+    Lexical,
+}
+
+#[derive(Copy, Clone)]
+pub struct Mode {
+    pub underscores: UnderscoreOrder,
+    pub descending: bool,
+    pub order: Order,
+    pub numbers: Numbers,
+}
+
 pub struct Path {
-    pub segments: Vec<Ident>,
+    pub segments: Vec<Segment>,
+}
+
+pub enum Segment {
+    Ident(Ident),
+    Lit(Lit),
+    Range(Range),
+    Tuple(Tuple),
+    // A `ref` or `mut` binding, which matches anything like `_` does.
+    Binding(PatIdent),
+    // The `..` in a tuple pattern, which matches any remaining elements.
+    Rest(PatRest),
+}
+
+pub struct Range {
+    pub pat: PatRange,
+    pub start: Option<Lit>,
+    pub end: Option<Lit>,
+    pub inclusive: bool,
+}
+
+pub struct Tuple {
+    pub pat: PatTuple,
+    pub elems: Vec<Path>,
+}
+
+// A literal behaves as a range containing just itself.
+struct Bounds<'a> {
+    start: Option<&'a Lit>,
+    end: Option<&'a Lit>,
+    inclusive: bool,
+}
+
+impl Segment {
+    pub fn span(&self) -> Span {
+        match self {
+            Segment::Ident(ident) => ident.span(),
+            Segment::Lit(lit) => lit.span(),
+            Segment::Range(range) => range.pat.span(),
+            Segment::Tuple(tuple) => tuple.pat.span(),
+            Segment::Binding(pat) => pat.span(),
+            Segment::Rest(pat) => pat.span(),
+        }
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        match self {
+            Segment::Ident(ident) => ident == "_",
+            Segment::Binding(_) | Segment::Rest(_) => true,
+            Segment::Lit(_) | Segment::Range(_) | Segment::Tuple(_) => false,
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Segment::Lit(_) | Segment::Range(_) => 0,
+            Segment::Tuple(_) => 1,
+            Segment::Ident(_) | Segment::Binding(_) | Segment::Rest(_) => 2,
+        }
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        match self {
+            Segment::Ident(_) | Segment::Tuple(_) | Segment::Binding(_) | Segment::Rest(_) => None,
+            Segment::Lit(lit) => Some(Bounds {
+                start: Some(lit),
+                end: Some(lit),
+                inclusive: true,
+            }),
+            Segment::Range(range) => Some(Bounds {
+                start: range.start.as_ref(),
+                end: range.end.as_ref(),
+                inclusive: range.inclusive,
+            }),
+        }
+    }
 }
 
-pub fn cmp(lhs: &Path, rhs: &Path, mode: UnderscoreOrder) -> Ordering {
+impl ToTokens for Segment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Segment::Ident(ident) => ident.to_tokens(tokens),
+            Segment::Lit(lit) => lit.to_tokens(tokens),
+            Segment::Range(range) => range.pat.to_tokens(tokens),
+            Segment::Tuple(tuple) => tuple.pat.to_tokens(tokens),
+            Segment::Binding(pat) => pat.to_tokens(tokens),
+            Segment::Rest(pat) => pat.to_tokens(tokens),
+        }
+    }
+}
+
+pub fn cmp(lhs: &Path, rhs: &Path, mode: Mode) -> Ordering {
     // Lexicographic ordering across path segments.
     for (lhs, rhs) in lhs.segments.iter().zip(&rhs.segments) {
-        match cmp_segment(&lhs.to_string(), &rhs.to_string(), mode) {
+        match cmp_segment(lhs, rhs, mode) {
             Ordering::Equal => {}
             non_eq => return non_eq,
         }
     }
 
-    lhs.segments.len().cmp(&rhs.segments.len())
+    let ordering = lhs.segments.len().cmp(&rhs.segments.len());
+    if mode.descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
 }
 
-fn cmp_segment(lhs: &str, rhs: &str, mode: UnderscoreOrder) -> Ordering {
-    // Sort `_` last.
-    match (lhs, rhs) {
-        ("_", "_") => return Ordering::Equal,
-        ("_", _) => return Ordering::Greater,
-        (_, "_") => return Ordering::Less,
-        (_, _) => {}
+fn cmp_segment(lhs: &Segment, rhs: &Segment, mode: Mode) -> Ordering {
+    // Sort `_` and catch-all bindings last, regardless of direction.
+    match (lhs.is_wildcard(), rhs.is_wildcard()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    let ordering = match (lhs, rhs) {
+        (Segment::Ident(lhs), Segment::Ident(rhs)) => {
+            cmp_name(&lhs.to_string(), &rhs.to_string(), mode)
+        }
+        (Segment::Lit(lhs), Segment::Lit(rhs)) => cmp_lit(lhs, rhs, mode),
+        (Segment::Lit(_) | Segment::Range(_), Segment::Lit(_) | Segment::Range(_)) => {
+            cmp_bounds(&lhs.bounds().unwrap(), &rhs.bounds().unwrap(), mode)
+        }
+        // Elements already compare in the requested direction.
+        (Segment::Tuple(lhs), Segment::Tuple(rhs)) => return cmp_tuple(lhs, rhs, mode),
+        // Literal patterns, then tuples, then named constants.
+        _ => lhs.rank().cmp(&rhs.rank()),
+    };
+    if mode.descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+// Lexicographic ordering across tuple elements.
+fn cmp_tuple(lhs: &Tuple, rhs: &Tuple, mode: Mode) -> Ordering {
+    for (lhs, rhs) in lhs.elems.iter().zip(&rhs.elems) {
+        match cmp(lhs, rhs, mode) {
+            Ordering::Equal => {}
+            non_eq => return non_eq,
+        }
     }
 
-    let mut lhs_atoms = iter_atoms(lhs);
-    let mut rhs_atoms = iter_atoms(rhs);
+    let ordering = lhs.elems.len().cmp(&rhs.elems.len());
+    if mode.descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+fn cmp_name(lhs: &str, rhs: &str, mode: Mode) -> Ordering {
+    if let Order::Ascii | Order::Bytes = mode.order {
+        return lhs.cmp(rhs);
+    }
+
+    let numbers = mode.numbers == Numbers::Natural;
+    let mut lhs_atoms = iter_atoms(lhs, numbers);
+    let mut rhs_atoms = iter_atoms(rhs, numbers);
 
     // Path segments can't be empty.
     let mut left = lhs_atoms.next().unwrap();
     let mut right = rhs_atoms.next().unwrap();
 
-    if mode == UnderscoreOrder::Last {
+    if mode.underscores == UnderscoreOrder::Last {
         // Compare leading underscores.
         match left.underscores().cmp(&right.underscores()) {
             Ordering::Equal => {}
@@ -66,3 +231,86 @@ fn cmp_segment(lhs: &str, rhs: &str, mode: UnderscoreOrder) -> Ordering {
         }
     }
 }
+
+fn cmp_lit(lhs: &Lit, rhs: &Lit, mode: Mode) -> Ordering {
+    match (lhs, rhs) {
+        (Lit::Str(lhs), Lit::Str(rhs)) => cmp_str(&lhs.value(), &rhs.value(), mode),
+        (Lit::ByteStr(lhs), Lit::ByteStr(rhs)) => lhs.value().cmp(&rhs.value()),
+        (Lit::CStr(lhs), Lit::CStr(rhs)) => lhs.value().cmp(&rhs.value()),
+        (Lit::Char(lhs), Lit::Char(rhs)) => lhs.value().cmp(&rhs.value()),
+        (Lit::Bool(lhs), Lit::Bool(rhs)) => lhs.value.cmp(&rhs.value),
+        // Byte literals and integer literals can both match a u8.
+        (lhs, rhs) => match (int_digits(lhs), int_digits(rhs)) {
+            (Some(lhs), Some(rhs)) => cmp_int(&lhs, &rhs),
+            // Literals of different types can't appear in the same match.
+            _ => Ordering::Equal,
+        },
+    }
+}
+
+// By lower bound, then by upper bound.
+fn cmp_bounds(lhs: &Bounds, rhs: &Bounds, mode: Mode) -> Ordering {
+    let start = match (lhs.start, rhs.start) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(lhs), Some(rhs)) => cmp_lit(lhs, rhs, mode),
+    };
+
+    start.then_with(|| match (lhs.end, rhs.end) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(l), Some(r)) => cmp_lit(l, r, mode).then(lhs.inclusive.cmp(&rhs.inclusive)),
+    })
+}
+
+/// Whether two literal or range segments match any value in common.
+pub fn overlaps(lhs: &Segment, rhs: &Segment, mode: Mode) -> bool {
+    // Identical literals are repeats, not overlaps.
+    if !matches!(lhs, Segment::Range(_)) && !matches!(rhs, Segment::Range(_)) {
+        return false;
+    }
+
+    let (Some(lhs), Some(rhs)) = (lhs.bounds(), rhs.bounds()) else {
+        return false;
+    };
+
+    let starts_before_end = |start: Option<&Lit>, end: &Bounds| match (start, end.end) {
+        (None, _) | (_, None) => true,
+        (Some(start), Some(limit)) => match cmp_lit(start, limit, mode) {
+            Ordering::Less => true,
+            Ordering::Equal => end.inclusive,
+            Ordering::Greater => false,
+        },
+    };
+
+    starts_before_end(lhs.start, &rhs) && starts_before_end(rhs.start, &lhs)
+}
+
+fn cmp_str(lhs: &str, rhs: &str, mode: Mode) -> Ordering {
+    // Unlike path segments, string literals can be empty.
+    match (lhs.is_empty(), rhs.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => cmp_name(lhs, rhs, mode),
+    }
+}
+
+fn int_digits(lit: &Lit) -> Option<String> {
+    match lit {
+        Lit::Int(lit) => Some(lit.base10_digits().to_owned()),
+        Lit::Byte(lit) => Some(lit.value().to_string()),
+        _ => None,
+    }
+}
+
+fn cmp_int(lhs: &str, rhs: &str) -> Ordering {
+    match (lhs.strip_prefix('-'), rhs.strip_prefix('-')) {
+        (Some(lhs), Some(rhs)) => cmp_numeric(rhs, lhs),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => cmp_numeric(lhs, rhs),
+    }
+}
@@ -8,15 +8,37 @@ pub enum Kind {
     Match,
     Struct,
     Let,
+    Impl,
+    Trait,
+    Mod,
+    Use,
+    Const,
+    Static,
+    StructExpr,
+    StructPat,
 }
 
 pub fn emit(err: &Error, kind: Kind, output: TokenStream) -> TokenStream {
-    let err = err.to_compile_error();
+    prepend(&err.to_compile_error(), kind, output)
+}
+
+pub fn prepend(extra: &proc_macro2::TokenStream, kind: Kind, output: TokenStream) -> TokenStream {
     let output = proc_macro2::TokenStream::from(output);
 
     let expanded = match kind {
-        Kind::Enum | Kind::Let | Kind::Struct => quote!(#err #output),
-        Kind::Match => quote!({ #err #output }),
+        Kind::Enum
+        | Kind::Let
+        | Kind::Struct
+        | Kind::Impl
+        | Kind::Trait
+        | Kind::Mod
+        | Kind::Use
+        | Kind::Const
+        | Kind::Static
+        | Kind::StructPat => {
+            quote!(#extra #output)
+        }
+        Kind::Match | Kind::StructExpr => quote!({ #extra #output }),
     };
 
     TokenStream::from(expanded)
@@ -0,0 +1,119 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Error, ItemEnum, Result};
+
+use crate::compare::{Path, Segment};
+use crate::format::{self, Position};
+
+pub fn order_const(item: &ItemEnum, span: Span) -> Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        let msg = "export is not supported on generic enums";
+        return Err(Error::new(span, msg));
+    }
+
+    let vis = &item.vis;
+    let ident = &item.ident;
+    let names = item
+        .variants
+        .iter()
+        .map(|variant| variant.ident.to_string());
+
+    Ok(quote! {
+        impl #ident {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            #vis const __REMAIN_EXPORT: &'static [&'static str] = &[#(#names),*];
+        }
+    })
+}
+
+// The order exported by the enum is only available to the compiler, not to
+// this macro, so the comparison happens during const evaluation. Each pair of
+// adjacent arms panics with the same message a misordering would produce, at
+// the location of the later arm. Arms naming something other than a variant
+// panic too, since they would otherwise sort last without complaint. An arm
+// that spells out its enum, like `E::A`, can be rejected right away if the
+// enum is not the one given to `by`.
+pub fn check_against(paths: &[Path], by: &syn::Path) -> Result<TokenStream> {
+    let mut checks = TokenStream::new();
+    let mut errors: Option<Error> = None;
+
+    let by_name = by
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    let enum_name = &by.segments.last().unwrap().ident;
+    for path in paths {
+        let last = path.segments.last().unwrap();
+        if last.is_wildcard() {
+            continue;
+        }
+        if let [.., Segment::Ident(prefix), _] = &*path.segments {
+            if prefix != enum_name && prefix != "Self" {
+                let error = format::variant_error(path, &by_name);
+                match &mut errors {
+                    None => errors = Some(error),
+                    Some(errors) => errors.combine(error),
+                }
+                continue;
+            }
+        }
+        let name = variant_name(path);
+        let msg = format!("{} is not a variant of {}", path, by_name);
+        let span = last.span();
+        checks.extend(quote_spanned! {span=>
+            if position(#name) == ORDER.len() {
+                ::core::panic!(#msg);
+            }
+        });
+    }
+
+    for pair in paths.windows(2) {
+        let prev = variant_name(&pair[0]);
+        let next = variant_name(&pair[1]);
+        let msg = format::message(&pair[1], Position::Before, &pair[0]);
+        let span = pair[1].segments.last().unwrap().span();
+        checks.extend(quote_spanned! {span=>
+            if position(#next) < position(#prev) {
+                ::core::panic!(#msg);
+            }
+        });
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    Ok(quote! {
+        const _: () = {
+            const ORDER: &[&str] = #by::__REMAIN_EXPORT;
+
+            const fn position(name: &str) -> usize {
+                let name = name.as_bytes();
+                let mut i = 0;
+                while i < ORDER.len() {
+                    let candidate = ORDER[i].as_bytes();
+                    if candidate.len() == name.len() {
+                        let mut j = 0;
+                        while j < name.len() && candidate[j] == name[j] {
+                            j += 1;
+                        }
+                        if j == name.len() {
+                            return i;
+                        }
+                    }
+                    i += 1;
+                }
+                ORDER.len()
+            }
+
+            #checks
+        };
+    })
+}
+
+fn variant_name(path: &Path) -> String {
+    path.segments.last().unwrap().to_string()
+}
@@ -1,9 +1,9 @@
 use proc_macro2::TokenStream;
-use quote::TokenStreamExt;
-use std::fmt::{self, Display};
+use quote::{ToTokens, TokenStreamExt};
+use std::fmt::{self, Display, Write as _};
 use syn::Error;
 
-use crate::compare::Path;
+use crate::compare::{Path, Segment, UnderscoreOrder};
 
 impl Display for Path {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -17,11 +17,118 @@ impl Display for Path {
     }
 }
 
-pub fn error(lesser: &Path, greater: &Path) -> Error {
+impl Display for Segment {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Segment::Ident(ident) => ident.fmt(formatter),
+            Segment::Lit(lit) => lit.to_token_stream().fmt(formatter),
+            Segment::Range(range) => {
+                if let Some(start) = &range.start {
+                    start.to_token_stream().fmt(formatter)?;
+                }
+                formatter.write_str(if range.inclusive { "..=" } else { ".." })?;
+                if let Some(end) = &range.end {
+                    end.to_token_stream().fmt(formatter)?;
+                }
+                Ok(())
+            }
+            Segment::Tuple(tuple) => {
+                formatter.write_str("(")?;
+                for (i, elem) in tuple.elems.iter().enumerate() {
+                    if i > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    elem.fmt(formatter)?;
+                }
+                if tuple.elems.len() == 1 {
+                    formatter.write_str(",")?;
+                }
+                formatter.write_str(")")
+            }
+            Segment::Binding(pat) => pat.to_token_stream().fmt(formatter),
+            Segment::Rest(_) => formatter.write_str(".."),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum Position {
+    Before,
+    After,
+}
+
+pub fn error(
+    wrong: &Path,
+    position: Position,
+    other: &Path,
+    underscores: Option<UnderscoreOrder>,
+) -> Error {
     let mut spans = TokenStream::new();
-    spans.append_all(&lesser.segments);
+    spans.append_all(&wrong.segments);
 
-    let msg = format!("{} should sort before {}", lesser, greater);
+    let mut msg = message(wrong, position, other);
+    match underscores {
+        Some(UnderscoreOrder::First) => msg.push_str(" (underscores first)"),
+        Some(UnderscoreOrder::Last) => msg.push_str(" (underscores last)"),
+        None => {}
+    }
 
     Error::new_spanned(spans, msg)
 }
+
+pub fn message(wrong: &Path, position: Position, other: &Path) -> String {
+    let position = match position {
+        Position::Before => "before",
+        Position::After => "after",
+    };
+    format!("{} should sort {} {}", wrong, position, other)
+}
+
+pub fn expected_order(first: &Path, expected: &[&Path]) -> Error {
+    let mut spans = TokenStream::new();
+    spans.append_all(&first.segments);
+
+    let mut msg = String::from("expected order:");
+    for path in expected {
+        write!(msg, "\n    {},", path).unwrap();
+    }
+
+    Error::new_spanned(spans, msg)
+}
+
+pub fn overlap_error(segment: &Segment, other: &Segment) -> Error {
+    let msg = format!("{} overlaps {}", segment, other);
+    Error::new_spanned(segment, msg)
+}
+
+pub fn duplicate_error(path: &Path, first: &Path) -> Error {
+    let mut spans = TokenStream::new();
+    spans.append_all(&path.segments);
+    let mut error = Error::new_spanned(spans, format!("duplicate {}", path));
+
+    let mut spans = TokenStream::new();
+    spans.append_all(&first.segments);
+    error.combine(Error::new_spanned(
+        spans,
+        format!("{} first appears here", first),
+    ));
+
+    error
+}
+
+pub fn guard_error(guarded: &Path, unguarded: &Path) -> Error {
+    let mut spans = TokenStream::new();
+    spans.append_all(&guarded.segments);
+    let msg = format!(
+        "{} with a guard should sort before {} without one",
+        guarded, unguarded,
+    );
+    Error::new_spanned(spans, msg)
+}
+
+pub fn variant_error(path: &Path, by: &str) -> Error {
+    let mut spans = TokenStream::new();
+    spans.append_all(&path.segments);
+    let msg = format!("{} is not a variant of {}", path, by);
+    Error::new_spanned(spans, msg)
+}
@@ -15,6 +15,37 @@
 //! Place a `#[remain::sorted]` attribute on enums, structs, match-expressions,
 //! or let-statements whose value is a match-expression.
 //!
+//! The attribute is also accepted on impl blocks and trait definitions, where it
+//! checks that the associated consts, functions, and types are sorted by name.
+//! Macro invocations among them may go anywhere.
+//!
+//! On an inline module, items of each kind are sorted separately: the `use`
+//! declarations by path, and the modules, functions, types, and so on by name.
+//! Impl blocks and macro invocations may go anywhere.
+//!
+//! On a `use` declaration, the imports within each brace-delimited group are
+//! sorted, at every level of nesting. The `self` import and glob imports may go
+//! anywhere within their group.
+//!
+//! On a `const` or `static` whose value is an array or slice literal, like a lookup
+//! table meant for `binary_search`, the elements must be sorted. Elements may be
+//! string, integer, or other literals, or tuples whose first element is one.
+//!
+//! Match arms are sorted by the path in their pattern, or by literal value for
+//! patterns that are string, byte string, character, or integer literals. Range
+//! patterns like `0..=9` sort by their lower bound, then upper bound, and adjacent
+//! ranges must not overlap unless the earlier one has an `if` guard.
+//!
+//! Tuple patterns like `(State::Idle, Event::Start)` sort element by element,
+//! with a `..` element sorting last like `_`, and reference patterns like
+//! `&Kind::A` sort by the pattern they refer to. A binding like `n @ 1..=9` sorts
+//! by its subpattern, while `ref` and `mut` bindings match anything and sort last
+//! like `_`.
+//!
+//! Arms with an `if` guard sort by their pattern. A guarded arm must come before
+//! an unguarded arm with the same key that matches everything with that key, like
+//! `Some(_)` after `Some(n) if n > 0`, since that arm would otherwise shadow it.
+//!
 //! Alternatively, import as `use remain::sorted;` and use `#[sorted]` as the
 //! attribute.
 //!
@@ -103,6 +134,53 @@
 //!    |     ^^^^^^^
 //! ```
 //!
+//! Every out-of-place element gets its own error, followed by the complete list
+//! of elements in the order they are expected to be written.
+//!
+//! # Arguments
+//!
+//! The attribute accepts arguments that change which order is expected.
+//!
+//! - `#[remain::sorted(desc)]` requires descending order, for example to keep the
+//!   newest entries first. A `_` arm still goes last.
+//!
+//! - `#[remain::sorted(order = "ascii")]` compares names byte by byte, so that all
+//!   uppercase letters sort before lowercase ones, `E10` before `E9`, and `FooBar`
+//!   before `Foo_Bar`. It ignores `numbers` and `underscores`. The default is
+//!   `order = "natural"`, which ignores case except to break ties.
+//!
+//! - `#[remain::sorted(order = "bytes")]` compares exactly like `Ord for str`, the
+//!   same as `ascii`, and promises ascending order: a sequence of string literals
+//!   that passes this check satisfies `windows(2).all(|w| w[0] <= w[1])`, so a
+//!   table checked this way can be searched with `binary_search`. It cannot be
+//!   combined with `desc`.
+//!
+//! - `#[remain::sorted(numbers = "lexical")]` compares digits like any other
+//!   character, so that `E10` sorts before `E9`. The default is
+//!   `numbers = "natural"`, which compares runs of digits by their numeric value.
+//!
+//! - `#[remain::sorted(underscores = "first")]` or `underscores = "last"` picks
+//!   where names with leading underscores, like `__Nonexhaustive`, must go. By
+//!   default either placement is accepted.
+//!
+//! - `#[sorted(or_patterns)]` on a match additionally requires the cases within
+//!   each or-pattern, like `A | B | C`, to be sorted. Arms are always sorted by
+//!   their first case.
+//!
+//! - `#[sorted(unique)]` on a match rejects arms that repeat an earlier pattern,
+//!   such as two `E::Aaa(..)` arms or two identical string literals, or that an
+//!   earlier arm like `E::Aaa(_)` makes unreachable. These are usually a mistake.
+//!
+//! - `#[remain::sorted(like_trait(a, b, c))]` on a trait impl requires the items to
+//!   follow the listed order, which is meant to mirror the order in which the trait
+//!   declares them.
+//!
+//! - `#[remain::sorted(export)]` on an enum leaves the order of its variants
+//!   unchecked and records it instead, so that a match annotated with
+//!   `#[sorted(by = path::to::Enum)]` is required to list its arms in the same
+//!   order as the variants. Arguments that pick an order, like `desc`, cannot be
+//!   combined with `by`, while `unique` still applies.
+//!
 //! # Compiler support
 //!
 //! The attribute on enums is supported on any rustc version 1.31+.
@@ -120,7 +198,10 @@
 //! As a stable alternative, this crate provides a function-level attribute
 //! called `#[remain::check]` which makes match-expression and let-statement
 //! attributes work on any rustc version 1.31+. Place this attribute on any
-//! function containing `#[sorted]` to make them work on a stable compiler.
+//! function containing `#[sorted]` to make them work on a stable compiler, or on
+//! an impl block or trait to cover all of its methods at once. On an inline
+//! module, it covers every function, method, and const initializer within,
+//! including those in nested modules.
 //!
 //! ```
 //! # use std::fmt::{self, Display};
@@ -142,6 +223,16 @@
 //! #
 //! # fn main() {}
 //! ```
+//!
+//! Within such a function, `#[sorted]` is also accepted on struct literal
+//! expressions, like `Config { a, b, c }`, which must then list their fields in
+//! alphabetical order. A `..base` at the end is allowed.
+//!
+//! Likewise for struct patterns, like `let Config { a, b, .. } = config;` or a match
+//! arm `Config { a, b, .. } => ...`, when the let-statement or arm carries
+//! `#[sorted]`. Struct patterns nested in others, like `Some(Config { a, b, .. })`,
+//! are checked too. If the value of such a let-statement is itself a
+//! match-expression or struct literal, both the pattern and the value are checked.
 
 #![doc(html_root_url = "https://docs.rs/remain/0.2.16")]
 #![allow(
@@ -159,10 +250,12 @@
 
 extern crate proc_macro;
 
+mod args;
 mod atom;
 mod check;
 mod compare;
 mod emit;
+mod export;
 mod format;
 mod parse;
 mod visit;
@@ -170,22 +263,26 @@ mod visit;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse::Nothing;
-use syn::{parse_macro_input, ItemFn};
+use syn::{parse_macro_input, Item};
 
+use crate::args::Args;
 use crate::emit::emit;
 use crate::parse::Input;
 
 #[proc_macro_attribute]
 pub fn sorted(args: TokenStream, input: TokenStream) -> TokenStream {
-    let _ = parse_macro_input!(args as Nothing);
+    let mut sorted_args = Args::default();
+    let parser = syn::meta::parser(|meta| sorted_args.parse_meta(&meta));
+    parse_macro_input!(args with parser);
     let mut input = parse_macro_input!(input as Input);
     let kind = input.kind();
 
-    let result = check::sorted(&mut input);
+    let result = check::sorted(&mut input, &sorted_args);
     let output = TokenStream::from(quote!(#input));
 
     match result {
-        Ok(()) => output,
+        Ok(extra) if extra.is_empty() => output,
+        Ok(extra) => emit::prepend(&extra, kind, output),
         Err(err) => emit(&err, kind, output),
     }
 }
@@ -193,9 +290,13 @@ pub fn sorted(args: TokenStream, input: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 pub fn check(args: TokenStream, input: TokenStream) -> TokenStream {
     let _ = parse_macro_input!(args as Nothing);
-    let mut input = parse_macro_input!(input as ItemFn);
-
-    visit::check(&mut input);
+    let mut input = parse_macro_input!(input as Item);
 
-    TokenStream::from(quote!(#input))
+    match visit::check(&mut input) {
+        Ok(()) => TokenStream::from(quote!(#input)),
+        Err(err) => {
+            let err = err.to_compile_error();
+            TokenStream::from(quote!(#err #input))
+        }
+    }
 }
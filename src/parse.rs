@@ -1,7 +1,7 @@
 use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, Error, Expr, Fields, Result, Stmt, Token, Visibility};
+use syn::{Attribute, Error, Expr, Fields, Item, Result, Stmt, Token};
 
 use crate::emit::Kind;
 
@@ -10,6 +10,14 @@ pub enum Input {
     Match(syn::ExprMatch),
     Struct(syn::ItemStruct),
     Let(syn::ExprMatch),
+    Impl(syn::ItemImpl),
+    Trait(syn::ItemTrait),
+    Mod(syn::ItemMod),
+    Use(syn::ItemUse),
+    Const(syn::ItemConst),
+    Static(syn::ItemStatic),
+    StructExpr(syn::ExprStruct),
+    StructPat(syn::PatStruct),
 }
 
 impl Input {
@@ -19,6 +27,14 @@ impl Input {
             Input::Match(_) => Kind::Match,
             Input::Struct(_) => Kind::Struct,
             Input::Let(_) => Kind::Let,
+            Input::Impl(_) => Kind::Impl,
+            Input::Trait(_) => Kind::Trait,
+            Input::Mod(_) => Kind::Mod,
+            Input::Use(_) => Kind::Use,
+            Input::Const(_) => Kind::Const,
+            Input::Static(_) => Kind::Static,
+            Input::StructExpr(_) => Kind::StructExpr,
+            Input::StructPat(_) => Kind::StructPat,
         }
     }
 }
@@ -48,17 +64,19 @@ impl Parse for Input {
             return Ok(Input::Let(expr));
         }
 
-        let _: Visibility = ahead.parse()?;
-        if ahead.peek(Token![enum]) {
-            return input.parse().map(Input::Enum);
-        } else if ahead.peek(Token![struct]) {
-            let input: syn::ItemStruct = input.parse()?;
-            if let Fields::Named(_) = &input.fields {
-                return Ok(Input::Struct(input));
+        match input.parse()? {
+            Item::Enum(item) => Ok(Input::Enum(item)),
+            Item::Struct(item) if matches!(item.fields, Fields::Named(_)) => {
+                Ok(Input::Struct(item))
             }
+            Item::Impl(item) => Ok(Input::Impl(item)),
+            Item::Trait(item) => Ok(Input::Trait(item)),
+            Item::Mod(item) if item.content.is_some() => Ok(Input::Mod(item)),
+            Item::Use(item) => Ok(Input::Use(item)),
+            Item::Const(item) => Ok(Input::Const(item)),
+            Item::Static(item) => Ok(Input::Static(item)),
+            _ => Err(unexpected()),
         }
-
-        Err(unexpected())
     }
 }
 
@@ -67,6 +85,14 @@ impl ToTokens for Input {
         match self {
             Input::Enum(item) => item.to_tokens(tokens),
             Input::Struct(item) => item.to_tokens(tokens),
+            Input::Impl(item) => item.to_tokens(tokens),
+            Input::Trait(item) => item.to_tokens(tokens),
+            Input::Mod(item) => item.to_tokens(tokens),
+            Input::Use(item) => item.to_tokens(tokens),
+            Input::Const(item) => item.to_tokens(tokens),
+            Input::Static(item) => item.to_tokens(tokens),
+            Input::StructExpr(expr) => expr.to_tokens(tokens),
+            Input::StructPat(pat) => pat.to_tokens(tokens),
             Input::Match(expr) | Input::Let(expr) => expr.to_tokens(tokens),
         }
     }
@@ -74,6 +100,6 @@ impl ToTokens for Input {
 
 fn unexpected() -> Error {
     let span = Span::call_site();
-    let msg = "expected enum, struct, or match expression";
+    let msg = "expected enum, struct, impl, trait, mod, use, const, static, or match expression";
     Error::new(span, msg)
 }
@@ -1,11 +1,32 @@
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::visit_mut::{self, VisitMut};
-use syn::{parse_quote, Attribute, Expr, ExprMatch, ItemFn, Local};
+use syn::{
+    parse_quote, Arm, Attribute, Block, Error, Expr, Item, ItemMod, Local, Meta, Pat, PatStruct,
+    Result, Stmt,
+};
 
+use crate::args::Args;
 use crate::parse::Input;
 
-pub fn check(input: &mut ItemFn) {
-    Checker.visit_item_fn_mut(input);
+pub fn check(input: &mut Item) -> Result<()> {
+    match input {
+        // An inline module is walked entirely, including nested modules.
+        Item::Fn(_)
+        | Item::Impl(_)
+        | Item::Trait(_)
+        | Item::Mod(ItemMod {
+            content: Some(_), ..
+        }) => {
+            Checker.visit_item_mut(input);
+            Ok(())
+        }
+        _ => {
+            let span = Span::call_site();
+            let msg = "expected fn, impl, trait, or inline mod";
+            Err(Error::new(span, msg))
+        }
+    }
 }
 
 struct Checker;
@@ -14,16 +35,19 @@ impl VisitMut for Checker {
     fn visit_expr_mut(&mut self, expr: &mut Expr) {
         visit_mut::visit_expr_mut(self, expr);
 
-        let Expr::Match(expr_match) = expr else {
-            return;
+        let (input, attr) = match expr {
+            Expr::Match(expr_match) => match take_sorted_attr(&mut expr_match.attrs) {
+                Some(attr) => (Input::Match(expr_match.clone()), attr),
+                None => return,
+            },
+            Expr::Struct(expr_struct) => match take_sorted_attr(&mut expr_struct.attrs) {
+                Some(attr) => (Input::StructExpr(expr_struct.clone()), attr),
+                None => return,
+            },
+            _ => return,
         };
 
-        if !take_sorted_attr(&mut expr_match.attrs) {
-            return;
-        }
-
-        let input = expr_match.clone();
-        check_and_insert_error(input, expr);
+        check_and_insert_error(input, parse_args(&attr), expr);
     }
 
     fn visit_local_mut(&mut self, local: &mut Local) {
@@ -33,38 +57,178 @@ impl VisitMut for Checker {
             return;
         };
 
-        let Expr::Match(expr_match) = init.expr.as_ref() else {
-            return;
+        let input = match init.expr.as_ref() {
+            Expr::Match(expr_match) => Input::Match(expr_match.clone()),
+            Expr::Struct(expr_struct) => Input::StructExpr(expr_struct.clone()),
+            _ => return,
         };
 
-        if !take_sorted_attr(&mut local.attrs) {
+        let Some(attr) = take_sorted_attr(&mut local.attrs) else {
             return;
+        };
+        let args = parse_args(&attr);
+
+        // The attribute applies to any struct pattern on the left as well.
+        let mut pat_extra = TokenStream::new();
+        if let Ok(args) = &args {
+            let mut pats = Vec::new();
+            struct_pats_mut(&mut local.pat, &mut pats);
+            for pat in pats {
+                pat_extra.extend(check_struct_pat(pat, args));
+            }
         }
 
-        let input = expr_match.clone();
         let expr = local.init.as_mut().unwrap().expr.as_mut();
-        check_and_insert_error(input, expr);
+        check_and_insert_error(input, args, expr);
+        if !pat_extra.is_empty() {
+            *expr = parse_quote!({
+                #pat_extra
+                #expr
+            });
+        }
+    }
+
+    fn visit_arm_mut(&mut self, arm: &mut Arm) {
+        visit_mut::visit_arm_mut(self, arm);
+
+        let Some(attr) = take_sorted_attr(&mut arm.attrs) else {
+            return;
+        };
+
+        let extra = check_struct_pats(&mut arm.pat, &attr).unwrap_or_else(|| {
+            let msg = "expected a struct pattern";
+            Error::new_spanned(&attr, msg).to_compile_error()
+        });
+        if !extra.is_empty() {
+            let body = &arm.body;
+            arm.body = parse_quote!({
+                #extra
+                #body
+            });
+        }
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        visit_mut::visit_block_mut(self, block);
+
+        // A let-statement whose value is not a match or struct literal may
+        // still have a struct pattern to check. Errors go in a statement of
+        // their own before it, since the value is optional.
+        let mut i = 0;
+        while i < block.stmts.len() {
+            if let Stmt::Local(local) = &mut block.stmts[i] {
+                if let Some(attr) = take_sorted_attr(&mut local.attrs) {
+                    let extra = check_struct_pats(&mut local.pat, &attr).unwrap_or_else(|| {
+                        let msg = "expected a match expression, struct literal, or struct pattern";
+                        Error::new_spanned(&attr, msg).to_compile_error()
+                    });
+                    if !extra.is_empty() {
+                        block.stmts.insert(i, parse_quote!({ #extra }));
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+        }
+    }
+}
+
+// The struct patterns within a pattern, looking through the patterns that wrap
+// them, like `Some(..)`, tuples, or-patterns, and references.
+fn struct_pats_mut<'a>(pat: &'a mut Pat, pats: &mut Vec<&'a mut PatStruct>) {
+    match pat {
+        Pat::Struct(pat) => pats.push(pat),
+        Pat::Guard(pat) => struct_pats_mut(&mut pat.pat, pats),
+        Pat::Ident(pat) => {
+            if let Some((_at, subpat)) = &mut pat.subpat {
+                struct_pats_mut(subpat, pats);
+            }
+        }
+        Pat::Or(pat) => {
+            for case in &mut pat.cases {
+                struct_pats_mut(case, pats);
+            }
+        }
+        Pat::Paren(pat) => struct_pats_mut(&mut pat.pat, pats),
+        Pat::Reference(pat) => struct_pats_mut(&mut pat.pat, pats),
+        Pat::Tuple(pat) => {
+            for elem in &mut pat.elems {
+                struct_pats_mut(elem, pats);
+            }
+        }
+        Pat::TupleStruct(pat) => {
+            for elem in &mut pat.elems {
+                struct_pats_mut(elem, pats);
+            }
+        }
+        Pat::Type(pat) => struct_pats_mut(&mut pat.pat, pats),
+        _ => {}
     }
 }
 
-fn take_sorted_attr(attrs: &mut Vec<Attribute>) -> bool {
+// None if the pattern contains no struct pattern for the attribute to check.
+fn check_struct_pats(pat: &mut Pat, attr: &Attribute) -> Option<TokenStream> {
+    let mut pats = Vec::new();
+    struct_pats_mut(pat, &mut pats);
+    if pats.is_empty() {
+        return None;
+    }
+
+    let args = match parse_args(attr) {
+        Ok(args) => args,
+        Err(err) => return Some(err.to_compile_error()),
+    };
+
+    let mut extra = TokenStream::new();
+    for pat in pats {
+        extra.extend(check_struct_pat(pat, &args));
+    }
+    Some(extra)
+}
+
+fn check_struct_pat(pat: &mut PatStruct, args: &Args) -> TokenStream {
+    let mut input = Input::StructPat(pat.clone());
+    let result = crate::check::sorted(&mut input, args);
+
+    // Keep the pattern with any #[unsorted] attributes removed.
+    if let Input::StructPat(checked) = input {
+        *pat = checked;
+    }
+
+    match result {
+        Ok(extra) => extra,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn take_sorted_attr(attrs: &mut Vec<Attribute>) -> Option<Attribute> {
     for i in 0..attrs.len() {
         let path = &attrs[i].path();
         let path = quote!(#path).to_string();
         if path == "sorted" || path == "remain :: sorted" {
-            attrs.remove(i);
-            return true;
+            return Some(attrs.remove(i));
         }
     }
 
-    false
+    None
 }
 
-fn check_and_insert_error(input: ExprMatch, out: &mut Expr) {
-    let mut input = Input::Match(input);
+fn parse_args(attr: &Attribute) -> Result<Args> {
+    let mut args = Args::default();
+    if let Meta::Path(_) = attr.meta {
+        return Ok(args);
+    }
+    attr.parse_nested_meta(|meta| args.parse_meta(&meta))?;
+    Ok(args)
+}
 
-    *out = match crate::check::sorted(&mut input) {
-        Ok(()) => parse_quote!(#input),
+fn check_and_insert_error(mut input: Input, args: Result<Args>, out: &mut Expr) {
+    *out = match args.and_then(|args| crate::check::sorted(&mut input, &args)) {
+        Ok(extra) if extra.is_empty() => parse_quote!(#input),
+        Ok(extra) => parse_quote!({
+            #extra
+            #input
+        }),
         Err(err) => {
             let err = err.to_compile_error();
             parse_quote!({
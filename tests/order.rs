@@ -56,3 +56,59 @@ enum LargeNumber {
     E1,
     E99999999999999999999999,
 }
+
+#[remain::sorted(desc)]
+enum Descending {
+    V10,
+    V9,
+    V2_Zzz,
+    V2_Aaa,
+    V1,
+}
+
+#[remain::sorted(order = "ascii")]
+enum Ascii {
+    Aaa,
+    Zzz,
+    aaa,
+    under_Score,
+    under_score,
+}
+
+#[remain::sorted(order = "ascii")]
+enum AsciiBytes {
+    E10,
+    E9,
+    FooBar,
+    Foo_Bar,
+}
+
+#[remain::sorted(order = "natural")]
+enum Natural {
+    Aaa,
+    aaa,
+    Zzz,
+}
+
+#[remain::sorted(numbers = "lexical")]
+enum Lexical {
+    E1,
+    E10,
+    E10_Aaa,
+    E2,
+    E9,
+}
+
+#[remain::sorted(underscores = "first")]
+enum ExplicitUnderscoresFirst {
+    __Nonexhaustive,
+    Aaa,
+    Bbb,
+}
+
+#[remain::sorted(underscores = "last")]
+enum ExplicitUnderscoresLast {
+    Aaa,
+    Bbb,
+    __Nonexhaustive,
+}
@@ -34,6 +34,77 @@ pub struct TestStruct {
     d: usize,
 }
 
+macro_rules! getter {
+    ($field:ident) => {
+        fn $field(&self) -> usize {
+            self.$field
+        }
+    };
+}
+
+macro_rules! method {
+    ($name:ident) => {
+        fn $name(&self) {}
+    };
+}
+
+#[remain::sorted]
+impl TestStruct {
+    const A: usize = 0;
+
+    getter!(d);
+
+    fn b(&self) -> usize {
+        self.b
+    }
+
+    #[unsorted]
+    fn ignored(&self) -> usize {
+        self.ignored
+    }
+
+    fn c(&self) -> usize {
+        self.c
+    }
+}
+
+#[remain::sorted]
+pub trait TestTrait {
+    const A: usize;
+
+    type B;
+
+    #[remain::unsorted]
+    fn ignored(&self);
+
+    fn c(&self) -> Self::B;
+
+    method!(e);
+
+    fn d(&self) {}
+}
+
+#[remain::sorted(like_trait(d, c, A, B))]
+impl TestTrait for TestStruct {
+    fn d(&self) {}
+
+    fn c(&self) -> Self::B {}
+
+    const A: usize = 0;
+
+    type B = ();
+
+    #[unsorted]
+    fn ignored(&self) {}
+}
+
+#[remain::sorted(export)]
+pub enum TestExport {
+    Start,
+    Middle,
+    End,
+}
+
 #[test]
 fn test_attrs() {
     fn is_partial_eq<T: PartialEq>() -> bool {
@@ -79,3 +150,461 @@ fn test_match() {
         _ => {}
     }
 }
+
+#[test]
+#[remain::check]
+fn test_by() {
+    let value = TestExport::Middle;
+
+    #[sorted(by = TestExport)]
+    match value {
+        TestExport::Start => {}
+        #[unsorted]
+        TestExport::End => {}
+        TestExport::Middle => {}
+    }
+
+    #[sorted(by = TestExport)]
+    let () = match value {
+        TestExport::Start => {}
+        TestExport::Middle => {}
+        TestExport::End => {}
+    };
+}
+
+#[test]
+#[remain::check]
+fn test_desc() {
+    let value = TestEnum::A;
+
+    #[sorted(desc)]
+    match value {
+        TestEnum::D => {}
+        TestEnum::C => {}
+        TestEnum::B => {}
+        _ => {}
+    }
+}
+
+#[test]
+#[remain::check]
+fn test_literals() {
+    let cmd = "add";
+    #[sorted]
+    match cmd {
+        "" => {}
+        "add" => {}
+        "Commit" => {}
+        "file2" => {}
+        "file10" => {}
+        "rm" => {}
+        _ => {}
+    }
+
+    let opcode = 0i32;
+    #[sorted]
+    match opcode {
+        -10 => {}
+        -2 => {}
+        0 => {}
+        0x10 => {}
+        1_000 => {}
+        _ => {}
+    }
+
+    let byte = b'a';
+    #[sorted]
+    match byte {
+        b'\n' => {}
+        b'0' => {}
+        100 => {}
+        b'z' => {}
+        _ => {}
+    }
+
+    let ch = 'a';
+    #[sorted]
+    match ch {
+        'A' => {}
+        'a' => {}
+        'z' => {}
+        _ => {}
+    }
+
+    let bytes: &[u8] = b"";
+    #[sorted]
+    match bytes {
+        b"GET" => {}
+        b"POST" => {}
+        b"get" => {}
+        _ => {}
+    }
+}
+
+#[test]
+#[remain::check]
+fn test_ranges() {
+    let value = 0u8;
+    #[sorted]
+    match value {
+        ..=b'\t' => {}
+        b'\n' => {}
+        11..b'0' => {}
+        b'0'..=b'9' => {}
+        b'a'..=b'z' => {}
+        200.. => {}
+        _ => {}
+    }
+
+    let ch = 'a';
+    #[sorted]
+    match ch {
+        'A'..='Z' => {}
+        '_' => {}
+        'a'..='z' => {}
+        _ => {}
+    }
+}
+
+#[test]
+#[remain::check]
+fn test_or_patterns() {
+    let value = TestEnum::A;
+
+    #[sorted(or_patterns)]
+    match value {
+        TestEnum::A | TestEnum::B => {}
+        TestEnum::C => {}
+        #[unsorted]
+        TestEnum::Ignored | TestEnum::AlsoIgnored => {}
+        TestEnum::D | TestEnum::__Nonexhaustive => {}
+    }
+}
+
+#[test]
+#[remain::check]
+fn test_tuples() {
+    let value = (TestEnum::A, 0);
+
+    #[sorted]
+    match &value {
+        (TestEnum::A, 0) => {}
+        (TestEnum::A, 1..=9) => {}
+        (TestEnum::A, _) => {}
+        &(TestEnum::B, 0) => {}
+        (TestEnum::B, ..) => {}
+        (TestEnum::C | TestEnum::D, _) => {}
+        (_, 0) => {}
+        _ => {}
+    }
+
+    let triple = (TestEnum::A, 0, 0);
+
+    #[sorted]
+    match triple {
+        (TestEnum::A, 0, 0) => {}
+        (TestEnum::A, ..) => {}
+        (TestEnum::B, _, 0) => {}
+        (.., 0) => {}
+        _ => {}
+    }
+
+    #[sorted]
+    match &value.0 {
+        &TestEnum::A => {}
+        &(TestEnum::B | TestEnum::C) => {}
+        TestEnum::D => {}
+        _ => {}
+    }
+}
+
+#[test]
+#[remain::check]
+fn test_bindings() {
+    let value = 0;
+
+    #[sorted]
+    let _ = match value {
+        0 => 0,
+        n @ 1..=9 => n,
+        mut n => {
+            n += 1;
+            n
+        }
+    };
+
+    let value = TestEnum::A;
+
+    #[sorted]
+    let _ = match value {
+        TestEnum::A => 0,
+        TestEnum::B => 1,
+        ref other => other as *const _ as usize,
+    };
+}
+
+#[test]
+#[remain::check]
+fn test_unique() {
+    let value = TestEnum::A;
+
+    #[sorted(unique)]
+    match value {
+        TestEnum::A => {}
+        TestEnum::B => {}
+        TestEnum::C => {}
+        _ => {}
+    }
+
+    let value = Some(0);
+
+    #[sorted(unique)]
+    match value {
+        None => {}
+        Some(0) => {}
+        Some(_) => {}
+    }
+
+    let nested = Some(Some(0));
+
+    #[sorted(unique)]
+    match nested {
+        None => {}
+        Some(None) => {}
+        Some(Some(_)) => {}
+    }
+}
+
+#[test]
+#[remain::check]
+fn test_guards() {
+    let value = Some(0);
+
+    #[sorted(unique)]
+    match value {
+        None => {}
+        Some(n) if n > 0 => {}
+        Some(n) if n < 0 => {}
+        Some(_) => {}
+    }
+
+    #[sorted]
+    let _ = match value {
+        None => 0,
+        Some(0) => 1,
+        Some(n) if n > 5 => 2,
+        Some(_) => 3,
+    };
+
+    let nested = Some(Some(0));
+
+    #[sorted]
+    match nested {
+        None => {}
+        Some(None) => {}
+        Some(x) if x.is_some() => {}
+        Some(_) => {}
+    }
+
+    let n = 0;
+    let small = true;
+
+    #[sorted]
+    match n {
+        0..=9 if small => {}
+        0..=9 => {}
+        10..=19 if small => {}
+        15..=30 => {}
+        _ => {}
+    }
+}
+
+#[remain::sorted]
+#[allow(dead_code, unused_imports)]
+mod test_mod {
+    use std::cmp::Ordering;
+    use std::fmt::{self, Display};
+
+    const A: usize = 0;
+    const B: usize = 1;
+
+    impl Display for Inner {
+        fn fmt(&self, _formatter: &mut fmt::Formatter) -> fmt::Result {
+            Ok(())
+        }
+    }
+
+    fn first() -> Ordering {
+        Ordering::Less
+    }
+
+    #[unsorted]
+    fn unsorted() {}
+
+    fn second() {}
+
+    mod inner {}
+
+    struct Inner;
+}
+
+#[remain::sorted]
+#[allow(unused_imports)]
+use std::collections::{
+    self,
+    btree_map::{self, Entry, Iter},
+    HashMap, *,
+};
+
+#[remain::sorted]
+const KEYWORDS: &[&str] = &["as", "async", "await", "break", "const"];
+
+#[remain::sorted]
+static OFFSETS: [i32; 4] = [-10, -1, 0, 10];
+
+#[remain::sorted]
+const NAMES: &[(u8, &str)] = &[(1, "one"), (2, "two"), (10, "ten")];
+
+#[remain::sorted(order = "bytes")]
+const TABLE: &[&str] = &["", "B", "Z", "_", "a", "a10", "a9"];
+
+#[test]
+fn test_bytes() {
+    assert!(TABLE.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(TABLE.binary_search(&"a9"), Ok(6));
+}
+
+#[derive(Default)]
+struct Config {
+    alpha: u8,
+    beta: u8,
+    gamma: u8,
+}
+
+#[test]
+#[remain::check]
+fn test_struct_expr() {
+    let beta = 1;
+
+    #[sorted]
+    let config = Config {
+        alpha: 0,
+        beta,
+        gamma: 2,
+    };
+
+    let _ = #[sorted]
+    Config {
+        beta: config.beta,
+        ..Default::default()
+    };
+}
+
+#[test]
+#[remain::check]
+fn test_struct_pat() {
+    let config = Config::default();
+
+    #[sorted]
+    let Config { alpha, gamma, .. } = config;
+    assert_eq!(alpha + gamma, 0);
+
+    match &config {
+        #[sorted]
+        Config { alpha: 0, beta, .. } if *beta > 0 => {}
+        #[sorted]
+        &Config { beta, gamma, .. } => {
+            let _ = (beta, gamma);
+        }
+    }
+
+    let option = Some(config);
+
+    match option {
+        #[sorted]
+        Some(Config { alpha: 0, gamma, .. } | Config { beta: 0, gamma, .. }) => {
+            let _ = gamma;
+        }
+        Some(_) => {}
+        None => {}
+    }
+
+    #[sorted]
+    let Some(Config { beta, gamma, .. }) = option else {
+        return;
+    };
+    assert_eq!(beta + gamma, 0);
+}
+
+#[remain::check]
+impl Config {
+    fn describe(&self) -> &'static str {
+        #[sorted]
+        match self.alpha {
+            0 => "zero",
+            1..=9 => "small",
+            _ => "large",
+        }
+    }
+}
+
+#[remain::check]
+trait Describe {
+    fn value(&self) -> TestEnum;
+
+    fn describe(&self) -> &'static str {
+        #[sorted]
+        match self.value() {
+            TestEnum::A => "a",
+            TestEnum::B => "b",
+            _ => "other",
+        }
+    }
+}
+
+#[test]
+fn test_check_impl() {
+    assert_eq!(Config::default().describe(), "zero");
+}
+
+#[remain::check]
+mod test_check_mod {
+    use super::TestEnum;
+
+    pub const CODE: u8 = #[sorted]
+    match 0 {
+        0 => 1,
+        _ => 2,
+    };
+
+    pub fn handle(value: &TestEnum) -> u8 {
+        #[sorted]
+        match value {
+            TestEnum::A => 0,
+            TestEnum::B => 1,
+            _ => CODE,
+        }
+    }
+
+    pub mod nested {
+        pub struct Handler;
+
+        impl Handler {
+            pub fn handle(value: u8) -> u8 {
+                #[sorted]
+                match value {
+                    0 => 10,
+                    1..=9 => 20,
+                    _ => 30,
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_check_mod() {
+    assert_eq!(test_check_mod::handle(&TestEnum::A), 0);
+    assert_eq!(test_check_mod::CODE, 1);
+    assert_eq!(test_check_mod::nested::Handler::handle(1), 20);
+}
@@ -0,0 +1,11 @@
+#[remain::check]
+fn main() {
+    let value = 0;
+
+    #[sorted]
+    match value {
+        n @ 10..=19 => {}
+        mut n => {}
+        n @ 0..=9 => {}
+    }
+}
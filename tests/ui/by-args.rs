@@ -0,0 +1,32 @@
+#[remain::sorted(export)]
+enum E {
+    Ccc,
+    Aaa,
+    Bbb,
+}
+
+#[remain::check]
+fn main() {
+    let value = E::Aaa;
+
+    #[sorted(by = E, desc)]
+    match value {
+        E::Bbb => {}
+        E::Aaa => {}
+        E::Ccc => {}
+    }
+
+    #[sorted(by = E, or_patterns)]
+    match value {
+        E::Ccc => {}
+        E::Bbb | E::Aaa => {}
+    }
+
+    #[sorted(by = E, unique)]
+    match value {
+        E::Ccc => {}
+        E::Aaa => {}
+        E::Aaa => {}
+        E::Bbb => {}
+    }
+}
@@ -0,0 +1,22 @@
+#[remain::sorted(export)]
+enum E {
+    Ccc,
+    Aaa,
+    Bbb,
+}
+
+enum F {
+    Aaa,
+    Xxx,
+}
+
+#[remain::check]
+fn main() {
+    let value = F::Aaa;
+
+    #[sorted(by = E)]
+    match value {
+        F::Aaa => {}
+        F::Xxx => {}
+    }
+}
@@ -0,0 +1,24 @@
+#[remain::sorted(export)]
+enum E {
+    Ccc,
+    Aaa,
+    Bbb,
+}
+
+enum F {
+    Aaa,
+    Xxx,
+}
+
+#[remain::check]
+fn main() {
+    use F::*;
+
+    let value = Aaa;
+
+    #[sorted(by = E)]
+    match value {
+        Aaa => {}
+        Xxx => {}
+    }
+}
@@ -0,0 +1,18 @@
+#[remain::sorted(export)]
+enum E {
+    Ccc,
+    Aaa,
+    Bbb,
+}
+
+#[remain::check]
+fn main() {
+    let value = E::Aaa;
+
+    #[sorted(by = E)]
+    match value {
+        E::Ccc => {}
+        E::Bbb => {}
+        E::Aaa => {}
+    }
+}
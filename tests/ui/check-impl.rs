@@ -0,0 +1,30 @@
+struct S;
+
+#[remain::check]
+impl S {
+    fn f(&self, value: u8) {
+        #[sorted]
+        match value {
+            1 => {}
+            0 => {}
+            _ => {}
+        }
+    }
+}
+
+#[remain::check]
+trait T {
+    fn f(&self, value: u8) {
+        #[sorted]
+        match value {
+            1 => {}
+            0 => {}
+            _ => {}
+        }
+    }
+}
+
+#[remain::check]
+struct NotChecked;
+
+fn main() {}
@@ -0,0 +1,21 @@
+#[remain::check]
+mod handlers {
+    pub fn handle(value: u8) {
+        #[sorted]
+        match value {
+            1 => {}
+            0 => {}
+            _ => {}
+        }
+    }
+
+    mod nested {
+        const CODE: u8 = #[sorted]
+        match 0 {
+            _ => 1,
+            0 => 0,
+        };
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[remain::sorted]
+const KEYWORDS: &[&str] = &["as", "await", "async", "break"];
+
+#[remain::sorted]
+static CODES: [(i32, &str); 3] = [(-1, "error"), (1, "ok"), (0, "none")];
+
+fn main() {}
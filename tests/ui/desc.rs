@@ -0,0 +1,12 @@
+use remain::sorted;
+
+#[sorted(desc)]
+enum E {
+    Ddd,
+    Bbb,
+    Ccc,
+    Aaa,
+    Eee,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[remain::sorted(export)]
+enum Generic<T> {
+    Bbb(T),
+    Aaa,
+}
+
+#[remain::sorted(export)]
+struct S {
+    a: u8,
+}
+
+#[remain::sorted(by = Generic)]
+enum E {
+    Aaa,
+}
+
+fn main() {}
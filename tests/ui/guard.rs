@@ -0,0 +1,29 @@
+#[remain::check]
+fn main() {
+    let value = Some(0);
+
+    #[sorted]
+    match value {
+        None => {}
+        Some(_) => {}
+        Some(n) if n > 0 => {}
+    }
+
+    #[sorted]
+    match value {
+        None => {}
+        Some(0) => {}
+        Some(n) if n > 5 => {}
+        Some(_) => {}
+    }
+
+    let nested = Some(Some(0));
+
+    #[sorted]
+    match nested {
+        None => {}
+        Some(None) => {}
+        Some(x) if x.is_some() => {}
+        Some(_) => {}
+    }
+}
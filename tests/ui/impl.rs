@@ -0,0 +1,31 @@
+use remain::sorted;
+
+struct S;
+
+#[sorted]
+impl S {
+    const AAA: u8 = 0;
+
+    fn ccc(&self) {}
+
+    const BBB: u8 = 0;
+
+    fn ddd(&self) {}
+}
+
+trait Trait {
+    type Aaa;
+    fn bbb(&self);
+    fn ccc(&self);
+}
+
+#[sorted]
+impl Trait for S {
+    fn ccc(&self) {}
+
+    type Aaa = u8;
+
+    fn bbb(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use remain::sorted;
+
+trait Trait {
+    fn ccc(&self);
+    fn aaa(&self);
+    fn bbb(&self);
+}
+
+struct S;
+
+#[sorted(like_trait(ccc, aaa, bbb))]
+impl Trait for S {
+    fn ccc(&self) {}
+    fn bbb(&self) {}
+    fn aaa(&self) {}
+}
+
+struct T;
+
+#[sorted(like_trait(ccc, aaa))]
+impl Trait for T {
+    fn ccc(&self) {}
+    fn aaa(&self) {}
+    fn bbb(&self) {}
+}
+
+#[sorted(like_trait(aaa, bbb))]
+enum E {
+    Aaa,
+    Bbb,
+}
+
+fn main() {}
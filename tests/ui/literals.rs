@@ -0,0 +1,27 @@
+#[remain::check]
+fn main() {
+    let cmd = "add";
+    #[sorted]
+    match cmd {
+        "add" => {}
+        "rm" => {}
+        "commit" => {}
+        _ => {}
+    }
+
+    let opcode = 0;
+    #[sorted]
+    match opcode {
+        -1 => {}
+        10 => {}
+        9 => {}
+        _ => {}
+    }
+
+    let value = 0.0;
+    #[sorted]
+    match value {
+        1.0 => {}
+        _ => {}
+    }
+}
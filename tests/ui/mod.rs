@@ -0,0 +1,14 @@
+#[remain::sorted]
+#[allow(dead_code, unused_imports)]
+mod m {
+    use std::fmt;
+    use std::cmp;
+
+    fn a() {}
+    fn c() {}
+    fn b() {}
+
+    struct S;
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use remain::sorted;
+
+#[sorted]
+enum E {
+    Aaa,
+    Ddd,
+    Bbb,
+    Eee,
+    Fff,
+    Ccc,
+    Ggg,
+    Aab,
+}
+
+fn main() {}
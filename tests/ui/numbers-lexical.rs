@@ -0,0 +1,15 @@
+use remain::sorted;
+
+#[sorted(numbers = "lexical")]
+enum E {
+    E1,
+    E9,
+    E10,
+}
+
+#[sorted(numbers = "roman")]
+enum F {
+    I,
+}
+
+fn main() {}
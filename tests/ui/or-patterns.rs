@@ -0,0 +1,17 @@
+enum E {
+    Aaa,
+    Bbb,
+    Ccc,
+    Ddd,
+}
+
+#[remain::check]
+fn main() {
+    let value = E::Aaa;
+
+    #[sorted(or_patterns)]
+    match value {
+        E::Aaa | E::Ccc | E::Bbb => {}
+        E::Ddd => {}
+    }
+}
@@ -0,0 +1,17 @@
+#![allow(non_camel_case_types)]
+
+use remain::sorted;
+
+#[sorted(order = "ascii")]
+enum E {
+    Aaa,
+    aaa,
+    Bbb,
+}
+
+#[sorted(order = "reverse")]
+enum F {
+    Aaa,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[remain::sorted(order = "bytes")]
+const TABLE: &[&str] = &["a", "B", "a9", "a10"];
+
+#[remain::sorted(order = "binary")]
+const OTHER: &[&str] = &[];
+
+#[remain::sorted(order = "bytes", desc)]
+const DESCENDING: &[&str] = &["b", "a"];
+
+#[remain::sorted(desc, order = "bytes")]
+const ALSO_DESCENDING: &[&str] = &["b", "a"];
+
+fn main() {}
@@ -0,0 +1,20 @@
+#[remain::check]
+fn main() {
+    let value = 0;
+
+    #[sorted]
+    match value {
+        0 => {}
+        10..=20 => {}
+        1..10 => {}
+        _ => {}
+    }
+
+    #[sorted]
+    match value {
+        0..=20 => {}
+        10..=30 => {}
+        30 => {}
+        _ => {}
+    }
+}
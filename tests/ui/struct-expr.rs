@@ -0,0 +1,15 @@
+struct Config {
+    alpha: u8,
+    beta: u8,
+    gamma: u8,
+}
+
+#[remain::check]
+fn main() {
+    #[sorted]
+    let _ = Config {
+        alpha: 0,
+        gamma: 2,
+        beta: 1,
+    };
+}
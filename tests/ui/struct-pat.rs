@@ -0,0 +1,48 @@
+struct Config {
+    alpha: u8,
+    beta: u8,
+    gamma: u8,
+}
+
+#[remain::check]
+fn main() {
+    let config = Config {
+        alpha: 0,
+        beta: 1,
+        gamma: 2,
+    };
+
+    #[sorted]
+    let Config { beta, alpha, .. } = config;
+
+    match config {
+        #[sorted]
+        Config { gamma, alpha, beta } => {}
+    }
+
+    #[sorted]
+    let Config { gamma, beta, .. } = Config {
+        alpha: 0,
+        beta: 1,
+        gamma: 2,
+    };
+
+    let option = Some(config);
+
+    match option {
+        #[sorted]
+        Some(Config { beta, alpha, .. }) => {}
+        None => {}
+    }
+
+    #[sorted]
+    let Some(Config { gamma, alpha, .. }) = option else {
+        return;
+    };
+
+    match 0 {
+        #[sorted]
+        0 => {}
+        _ => {}
+    }
+}
@@ -0,0 +1,14 @@
+use remain::sorted;
+
+#[sorted]
+trait Trait {
+    const AAA: u8;
+
+    fn ccc(&self);
+
+    type Bbb;
+
+    fn ddd(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+enum State {
+    Idle,
+    Running,
+}
+
+enum Event {
+    Start,
+    Stop,
+}
+
+#[remain::check]
+fn main() {
+    let value = (State::Idle, Event::Start);
+
+    #[sorted]
+    match &value {
+        (State::Idle, Event::Stop) => {}
+        (State::Idle, Event::Start) => {}
+        &(State::Running, _) => {}
+    }
+}
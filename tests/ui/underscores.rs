@@ -0,0 +1,22 @@
+use remain::sorted;
+
+#[sorted(underscores = "first")]
+enum E {
+    Aaa,
+    Bbb,
+    __Nonexhaustive,
+}
+
+#[sorted(underscores = "last")]
+enum F {
+    __Nonexhaustive,
+    Aaa,
+    Bbb,
+}
+
+#[sorted(underscores = "middle")]
+enum G {
+    Aaa,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+enum E {
+    Aaa(u8),
+    Bbb,
+}
+
+#[remain::check]
+fn main() {
+    #[sorted(unique)]
+    match E::Bbb {
+        E::Aaa(..) => {}
+        E::Aaa(..) => {}
+        E::Bbb => {}
+    }
+
+    #[sorted(unique)]
+    match "" {
+        "a" => {}
+        "b" => {}
+        "a" => {}
+        _ => {}
+    }
+}
@@ -1,10 +1,10 @@
 #[remain::check]
 fn main() {
-    let value = 0;
+    let value = [0];
 
     #[sorted]
     match value {
-        0..=20 => {}
+        [0] => {}
         _ => {}
     }
 }
@@ -0,0 +1,5 @@
+#[remain::sorted]
+#[allow(unused_imports)]
+use std::collections::{btree_map::{Iter, Entry}, HashMap, BTreeMap};
+
+fn main() {}